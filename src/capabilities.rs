@@ -14,6 +14,8 @@ pub trait ClientCapabilitiesExt {
     fn has_push_configuration_support(&self) -> bool;
 
     fn has_file_watching_support(&self) -> bool;
+
+    fn has_inlay_hint_refresh_support(&self) -> bool;
 }
 
 impl ClientCapabilitiesExt for ClientCapabilities {
@@ -65,6 +67,14 @@ impl ClientCapabilitiesExt for ClientCapabilities {
             .and_then(|cap| cap.dynamic_registration)
             == Some(true)
     }
+
+    fn has_inlay_hint_refresh_support(&self) -> bool {
+        self.workspace
+            .as_ref()
+            .and_then(|cap| cap.inlay_hint.as_ref())
+            .and_then(|cap| cap.refresh_support)
+            == Some(true)
+    }
 }
 
 #[cfg(test)]
@@ -72,7 +82,8 @@ mod tests {
     use super::*;
     use lsp_types::{
         DocumentSymbolClientCapabilities, GotoCapability, HoverClientCapabilities,
-        TextDocumentClientCapabilities, WindowClientCapabilities,
+        InlayHintWorkspaceClientCapabilities, TextDocumentClientCapabilities,
+        WindowClientCapabilities, WorkspaceClientCapabilities,
     };
 
     #[test]
@@ -155,4 +166,24 @@ mod tests {
         let capabilities = ClientCapabilities::default();
         assert!(!capabilities.has_hover_markdown_support());
     }
+
+    #[test]
+    fn test_has_inlay_hint_refresh_support_true() {
+        let capabilities = ClientCapabilities {
+            workspace: Some(WorkspaceClientCapabilities {
+                inlay_hint: Some(InlayHintWorkspaceClientCapabilities {
+                    refresh_support: Some(true),
+                }),
+                ..WorkspaceClientCapabilities::default()
+            }),
+            ..ClientCapabilities::default()
+        };
+        assert!(capabilities.has_inlay_hint_refresh_support());
+    }
+
+    #[test]
+    fn test_has_inlay_hint_refresh_support_false() {
+        let capabilities = ClientCapabilities::default();
+        assert!(!capabilities.has_inlay_hint_refresh_support());
+    }
 }
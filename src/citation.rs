@@ -3,11 +3,21 @@ mod entry;
 pub(crate) mod field;
 mod output;
 
-use unicode_normalization::UnicodeNormalization;
+use chrono::Datelike;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
 
 use crate::syntax::bibtex;
 
-use self::{driver::Driver, output::Inline};
+use self::{
+    driver::Driver,
+    entry::EntryData,
+    field::{
+        author::AuthorField,
+        date::{DateField, DateFieldData},
+        text::TextField,
+    },
+    output::Inline,
+};
 
 #[must_use]
 pub fn render(entry: &bibtex::Entry) -> Option<String> {
@@ -33,5 +43,104 @@ pub fn render(entry: &bibtex::Entry) -> Option<String> {
     }
 }
 
+#[must_use]
+pub fn render_short(entry: &bibtex::Entry) -> Option<String> {
+    let mut data = EntryData::from(entry);
+    let names = find_short_names(&mut data)?;
+    let output = match find_year(&mut data) {
+        Some(year) => format!("{names} {year}"),
+        None => names,
+    };
+
+    Some(output.nfc().collect())
+}
+
+#[must_use]
+pub fn render_detail(entry: &bibtex::Entry) -> Option<String> {
+    let mut data = EntryData::from(entry);
+    let names = find_short_names(&mut data);
+    let year = find_year(&mut data);
+    let title = data.text.remove(&TextField::Title).map(|title| title.text);
+
+    let head = match (names, year) {
+        (Some(names), Some(year)) => Some(format!("{names} ({year})")),
+        (Some(names), None) => Some(names),
+        (None, Some(year)) => Some(year.to_string()),
+        (None, None) => None,
+    };
+
+    let output = match (head, title) {
+        (Some(head), Some(title)) => format!("{head}: {title}"),
+        (Some(head), None) => head,
+        (None, Some(title)) => title,
+        (None, None) => return None,
+    };
+
+    Some(output.nfc().collect())
+}
+
+#[must_use]
+pub fn render_search_text(entry: &bibtex::Entry) -> String {
+    let mut data = EntryData::from(entry);
+    let mut parts = Vec::new();
+    for field in [AuthorField::Author, AuthorField::Editor] {
+        if let Some(authors) = data.author.remove(&field) {
+            for name in &authors.authors {
+                parts.push(name.display_full().to_string());
+            }
+        }
+    }
+
+    for field in [
+        TextField::Title,
+        TextField::Journal,
+        TextField::JournalTitle,
+    ] {
+        if let Some(text) = data.text.remove(&field) {
+            parts.push(text.text);
+        }
+    }
+
+    if let Some(year) = find_year(&mut data) {
+        parts.push(year.to_string());
+    }
+
+    parts
+        .join(" ")
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn find_short_names(data: &mut EntryData) -> Option<String> {
+    let authors = data
+        .author
+        .remove(&AuthorField::Author)
+        .or_else(|| data.author.remove(&AuthorField::Editor))?;
+
+    let names = match authors.authors.as_slice() {
+        [] => return None,
+        [first] => first.surname().to_string(),
+        [first, second] => format!("{} and {}", first.surname(), second.surname()),
+        [first, ..] => format!("{} et al.", first.surname()),
+    };
+
+    Some(names)
+}
+
+fn find_year(data: &mut EntryData) -> Option<i32> {
+    [DateField::Date, DateField::Year]
+        .iter()
+        .filter_map(|field| data.date.remove(field))
+        .find_map(|date| match date {
+            DateFieldData::Date(date) => Some(date.year()),
+            DateFieldData::Year(year) => Some(year),
+            DateFieldData::Month(_) | DateFieldData::Other(_) => None,
+        })
+}
+
 #[cfg(test)]
 mod tests;
@@ -10,6 +10,46 @@ fn render_entry(input: &str) -> String {
     super::render(&entry).unwrap()
 }
 
+fn render_short_entry(input: &str) -> Option<String> {
+    let green = bibtex::parse(input);
+    let root = bibtex::Root::cast(bibtex::SyntaxNode::new_root(green)).unwrap();
+    let entry = root.entries().next().unwrap();
+    super::render_short(&entry)
+}
+
+#[test]
+fn short_single_author() {
+    assert_eq!(
+        render_short_entry("@book{foo, author = {Knuth, Donald E.}, year = {1984}}").as_deref(),
+        Some("Knuth 1984")
+    );
+}
+
+#[test]
+fn short_two_authors() {
+    assert_eq!(
+        render_short_entry("@article{foo, author = {Foo Bar and Baz Qux}, date = {2020-01-02}}")
+            .as_deref(),
+        Some("Bar and Qux 2020")
+    );
+}
+
+#[test]
+fn short_many_authors() {
+    assert_eq!(
+        render_short_entry(
+            "@article{foo, author = {Rivest, R. L. and Shamir, A. and Adleman, L.}}"
+        )
+        .as_deref(),
+        Some("Rivest et al.")
+    );
+}
+
+#[test]
+fn short_no_author() {
+    assert_eq!(render_short_entry("@article{foo, year = {2020}}"), None);
+}
+
 #[test]
 fn article_rivest_1978() {
     assert_snapshot!(render_entry(
@@ -8,9 +8,9 @@ use std::sync::Arc;
 
 use lsp_types::{Diagnostic, Url};
 use multimap::MultiMap;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::{Options, Workspace};
+use crate::{find_glossary_names, Options, Workspace};
 
 pub use self::debouncer::{DiagnosticsDebouncer, DiagnosticsMessage};
 
@@ -22,15 +22,37 @@ use self::{
 #[derive(Default)]
 pub struct DiagnosticsManager {
     static_diagnostics: FxHashMap<Arc<Url>, MultiMap<Arc<Url>, Diagnostic>>,
+    glossary_names_by_uri: FxHashMap<Arc<Url>, FxHashSet<String>>,
     chktex_diagnostics: MultiMap<Arc<Url>, Diagnostic>,
 }
 
 impl DiagnosticsManager {
-    pub fn update_static(&mut self, workspace: &Workspace, uri: Arc<Url>) {
+    /// Updates the static diagnostics of a changed document.
+    /// The other documents of its project are only analyzed again
+    /// if the glossary entries they were checked against have changed.
+    pub fn update_static(&mut self, project: &Workspace, uri: Arc<Url>) {
+        let glossary_names = find_glossary_names(project);
+        for other_uri in project.documents_by_uri.keys() {
+            if *other_uri == uri
+                || self.glossary_names_by_uri.get(other_uri) != Some(&glossary_names)
+            {
+                self.update_document_static(project, Arc::clone(other_uri), &glossary_names);
+            }
+        }
+    }
+
+    fn update_document_static(
+        &mut self,
+        project: &Workspace,
+        uri: Arc<Url>,
+        glossary_names: &FxHashSet<String>,
+    ) {
         let mut diagnostics_by_uri = MultiMap::new();
-        analyze_build_log_static(workspace, &mut diagnostics_by_uri, &uri);
-        analyze_bibtex_static(workspace, &mut diagnostics_by_uri, &uri);
-        analyze_latex_static(workspace, &mut diagnostics_by_uri, &uri);
+        analyze_build_log_static(project, &mut diagnostics_by_uri, &uri);
+        analyze_bibtex_static(project, &mut diagnostics_by_uri, &uri);
+        analyze_latex_static(project, &mut diagnostics_by_uri, &uri, glossary_names);
+        self.glossary_names_by_uri
+            .insert(Arc::clone(&uri), glossary_names.clone());
         self.static_diagnostics.insert(uri, diagnostics_by_uri);
     }
 
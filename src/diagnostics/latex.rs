@@ -3,6 +3,7 @@ use std::sync::Arc;
 use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Url};
 use multimap::MultiMap;
 use rowan::{ast::AstNode, NodeOrToken, TextRange};
+use rustc_hash::FxHashSet;
 
 use crate::{syntax::latex, Document, LineIndexExt, Workspace};
 
@@ -10,6 +11,7 @@ pub fn analyze_latex_static(
     workspace: &Workspace,
     diagnostics_by_uri: &mut MultiMap<Arc<Url>, Diagnostic>,
     uri: &Url,
+    glossary_names: &FxHashSet<String>,
 ) -> Option<()> {
     let document = workspace.documents_by_uri.get(uri)?;
     if !document.uri.as_str().ends_with(".tex") {
@@ -21,6 +23,9 @@ pub fn analyze_latex_static(
     for node in latex::SyntaxNode::new_root(data.green.clone()).descendants() {
         analyze_environment(document, diagnostics_by_uri, node.clone())
             .or_else(|| analyze_curly_group(document, diagnostics_by_uri, &node))
+            .or_else(|| {
+                analyze_glossary_reference(document, diagnostics_by_uri, &node, glossary_names)
+            })
             .or_else(|| {
                 if node.kind() == latex::ERROR && node.first_token()?.text() == "}" {
                     diagnostics_by_uri.insert(
@@ -128,3 +133,40 @@ fn analyze_curly_group(
 
     Some(())
 }
+
+fn analyze_glossary_reference(
+    document: &Document,
+    diagnostics_by_uri: &mut MultiMap<Arc<Url>, Diagnostic>,
+    node: &latex::SyntaxNode,
+    glossary_names: &FxHashSet<String>,
+) -> Option<()> {
+    let name = latex::GlossaryEntryReference::cast(node.clone())
+        .and_then(|reference| reference.name())
+        .or_else(|| latex::AcronymReference::cast(node.clone())?.name())?
+        .key()?;
+
+    // Entries can also be loaded from external resources (e.g. bib2gls),
+    // so only check the keys if the project defines entries itself.
+    if glossary_names.is_empty() || glossary_names.contains(&name.to_string()) {
+        return Some(());
+    }
+
+    diagnostics_by_uri.insert(
+        Arc::clone(&document.uri),
+        Diagnostic {
+            range: document
+                .line_index
+                .line_col_lsp_range(latex::small_range(&name)),
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::Number(9)),
+            code_description: None,
+            source: Some("texlab".to_string()),
+            message: "Undefined glossary entry or acronym".to_string(),
+            related_information: None,
+            tags: None,
+            data: None,
+        },
+    );
+
+    Some(())
+}
@@ -93,7 +93,7 @@ impl Document {
         let line_index = Arc::new(LineIndex::new(&text));
         let data = match language {
             DocumentLanguage::Latex => {
-                let green = latex::parse(&text).green;
+                let green = latex::parse(&text, &environment.options.experimental).green;
                 let root = latex::SyntaxNode::new_root(green.clone());
 
                 let base_uri = match &environment.options.root_directory {
@@ -1,4 +1,7 @@
 mod build;
+mod call_hierarchy;
+mod code_lens;
+mod color;
 #[cfg(feature = "completion")]
 mod completion;
 mod cursor;
@@ -9,10 +12,15 @@ mod formatting;
 mod forward_search;
 mod highlight;
 mod hover;
+mod inlay_hint;
 mod link;
+mod linked_editing;
 mod lsp_kinds;
+mod project_tree;
 mod reference;
 mod rename;
+mod selection_range;
+mod signature;
 mod symbol;
 
 use std::sync::Arc;
@@ -25,6 +33,9 @@ use crate::{Document, Workspace};
 pub use self::completion::{complete, CompletionItemData, COMPLETION_LIMIT};
 pub use self::{
     build::{BuildEngine, BuildParams, BuildResult, BuildStatus},
+    call_hierarchy::{find_incoming_calls, find_outgoing_calls, prepare_call_hierarchy},
+    code_lens::find_code_lenses,
+    color::{find_color_presentations, find_document_colors},
     definition::goto_definition,
     execute_command::execute_command,
     folding::find_foldings,
@@ -32,10 +43,15 @@ pub use self::{
     forward_search::{execute_forward_search, ForwardSearchResult, ForwardSearchStatus},
     highlight::find_document_highlights,
     hover::find_hover,
+    inlay_hint::find_inlay_hints,
     link::find_document_links,
+    linked_editing::find_linked_editing_ranges,
+    project_tree::{find_project_tree, ProjectTreeNode, ProjectTreeNodeKind, ProjectTreeParams},
     reference::find_all_references,
     rename::{prepare_rename_all, rename_all},
-    symbol::{find_document_symbols, find_workspace_symbols},
+    selection_range::find_selection_ranges,
+    signature::find_signature_help,
+    symbol::{find_document_symbols, find_workspace_symbols, WorkspaceSymbolIndex},
 };
 
 #[derive(Clone)]
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams, Range,
+    SymbolKind, Url,
+};
+use rowan::{TextRange, TextSize};
+use rustc_hash::FxHashMap;
+
+use crate::{syntax::latex::ExplicitLink, Document, LineIndexExt, Workspace};
+
+use super::FeatureRequest;
+
+pub fn prepare_call_hierarchy(
+    request: FeatureRequest<CallHierarchyPrepareParams>,
+) -> Option<Vec<CallHierarchyItem>> {
+    let main_document = request.main_document();
+    let offset = main_document
+        .line_index
+        .offset_lsp(request.params.text_document_position_params.position);
+
+    let document = main_document
+        .data
+        .as_latex()
+        .and_then(|data| {
+            data.extras
+                .explicit_links
+                .iter()
+                .filter(|link| link.stem_range.contains_inclusive(offset))
+                .find_map(|link| find_link_target(&request.workspace, link))
+        })
+        .unwrap_or(main_document);
+
+    Some(vec![create_item(document)])
+}
+
+pub fn find_incoming_calls(
+    request: FeatureRequest<CallHierarchyIncomingCallsParams>,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let mut calls = Vec::new();
+    for document in request.workspace.documents_by_uri.values() {
+        let from_ranges = find_link_ranges(&request.workspace, document)
+            .remove(&request.uri)
+            .unwrap_or_default();
+
+        if !from_ranges.is_empty() {
+            calls.push(CallHierarchyIncomingCall {
+                from: create_item(document),
+                from_ranges,
+            });
+        }
+    }
+
+    Some(calls)
+}
+
+pub fn find_outgoing_calls(
+    request: FeatureRequest<CallHierarchyOutgoingCallsParams>,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let calls = find_link_ranges(&request.workspace, request.main_document())
+        .into_iter()
+        .filter_map(|(uri, from_ranges)| {
+            let document = request.workspace.documents_by_uri.get(&uri)?;
+            Some(CallHierarchyOutgoingCall {
+                to: create_item(document),
+                from_ranges,
+            })
+        })
+        .collect();
+
+    Some(calls)
+}
+
+fn find_link_target<'a>(workspace: &'a Workspace, link: &ExplicitLink) -> Option<&'a Document> {
+    link.targets
+        .iter()
+        .find_map(|target| workspace.documents_by_uri.get(target))
+}
+
+fn find_link_ranges(workspace: &Workspace, document: &Document) -> FxHashMap<Arc<Url>, Vec<Range>> {
+    let mut ranges_by_uri: FxHashMap<Arc<Url>, Vec<Range>> = FxHashMap::default();
+    if let Some(data) = document.data.as_latex() {
+        for link in &data.extras.explicit_links {
+            if let Some(target) = find_link_target(workspace, link) {
+                ranges_by_uri
+                    .entry(Arc::clone(&target.uri))
+                    .or_default()
+                    .push(document.line_index.line_col_lsp_range(link.stem_range));
+            }
+        }
+    }
+
+    ranges_by_uri
+}
+
+fn create_item(document: &Document) -> CallHierarchyItem {
+    let name = document
+        .uri
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|name| !name.is_empty())
+        .map_or_else(|| document.uri.to_string(), ToString::to_string);
+
+    let text_range = TextRange::up_to(TextSize::of(document.text.as_str()));
+    let range = document.line_index.line_col_lsp_range(text_range);
+    let selection_range = document
+        .line_index
+        .line_col_lsp_range(TextRange::empty(TextSize::from(0)));
+
+    CallHierarchyItem {
+        name,
+        kind: SymbolKind::FILE,
+        tags: None,
+        detail: None,
+        uri: document.uri.as_ref().clone(),
+        range,
+        selection_range,
+        data: None,
+    }
+}
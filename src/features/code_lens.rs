@@ -0,0 +1,85 @@
+use lsp_types::{CodeLens, CodeLensParams, Command};
+use rowan::ast::AstNode;
+
+use crate::{
+    syntax::{
+        bibtex::{self, HasName},
+        latex,
+    },
+    DocumentData, LineIndexExt,
+};
+
+use super::{
+    reference::{ReferenceIndex, ReferenceKind},
+    FeatureRequest,
+};
+
+pub fn find_code_lenses(request: FeatureRequest<CodeLensParams>) -> Vec<CodeLens> {
+    let references = ReferenceIndex::new(&request.workspace);
+    let main_document = request.main_document();
+
+    let mut definitions = Vec::new();
+    match &main_document.data {
+        DocumentData::Latex(data) => {
+            for name in data
+                .extras
+                .label_names
+                .iter()
+                .filter(|name| name.is_definition)
+            {
+                definitions.push((name.range, ReferenceKind::Label, name.text.to_string()));
+            }
+
+            latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(latex::CommandDefinition::cast)
+                .filter_map(|def| def.defined_command())
+                .for_each(|command| {
+                    definitions.push((
+                        command.text_range(),
+                        ReferenceKind::Command,
+                        command.text().into(),
+                    ));
+                });
+        }
+        DocumentData::Bibtex(data) => {
+            bibtex::SyntaxNode::new_root(data.green.clone())
+                .children()
+                .filter_map(bibtex::Entry::cast)
+                .filter_map(|entry| entry.name_token())
+                .for_each(|key| {
+                    definitions.push((key.text_range(), ReferenceKind::Entry, key.text().into()));
+                });
+        }
+        DocumentData::BuildLog(_) => {}
+    };
+
+    definitions
+        .into_iter()
+        .map(|(range, kind, name)| {
+            let locations = references.find(&request.workspace, kind, &name);
+            let title = match locations.len() {
+                0 => "unused".to_string(),
+                1 => "1 reference".to_string(),
+                count => format!("{} references", count),
+            };
+
+            let range = main_document.line_index.line_col_lsp_range(range);
+            let arguments = vec![
+                serde_json::to_value(main_document.uri.as_ref()).unwrap(),
+                serde_json::to_value(range.start).unwrap(),
+                serde_json::to_value(locations).unwrap(),
+            ];
+
+            CodeLens {
+                range,
+                command: Some(Command::new(
+                    title,
+                    "texlab.showReferences".into(),
+                    Some(arguments),
+                )),
+                data: None,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,291 @@
+use lsp_types::{
+    Color, ColorInformation, ColorPresentation, ColorPresentationParams, DocumentColorParams,
+    TextEdit,
+};
+use rowan::{ast::AstNode, TextRange};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    syntax::latex::{self, HasCurly},
+    LineIndexExt, Workspace, LANGUAGE_DATA,
+};
+
+use super::{symbol::ProjectOrdering, FeatureRequest};
+
+type Rgb = [f32; 3];
+
+const WHITE: Rgb = [1.0, 1.0, 1.0];
+
+pub fn find_document_colors(request: FeatureRequest<DocumentColorParams>) -> Vec<ColorInformation> {
+    let mut colors = Vec::new();
+    let main_document = request.main_document();
+    let data = match main_document.data.as_latex() {
+        Some(data) => data,
+        None => return colors,
+    };
+
+    let user_colors = find_user_colors(&request.workspace);
+    for node in latex::SyntaxNode::new_root(data.green.clone()).descendants() {
+        if let Some((range, rgb)) = latex::ColorReference::cast(node.clone())
+            .and_then(|color| find_reference_color(&user_colors, &color))
+            .or_else(|| {
+                latex::ColorDefinition::cast(node)
+                    .and_then(|color| find_definition_color(&user_colors, &color))
+            })
+        {
+            colors.push(ColorInformation {
+                range: main_document.line_index.line_col_lsp_range(range),
+                color: Color {
+                    red: rgb[0],
+                    green: rgb[1],
+                    blue: rgb[2],
+                    alpha: 1.0,
+                },
+            });
+        }
+    }
+
+    colors
+}
+
+pub fn find_color_presentations(
+    request: FeatureRequest<ColorPresentationParams>,
+) -> Vec<ColorPresentation> {
+    let main_document = request.main_document();
+    let range = main_document
+        .line_index
+        .offset_lsp_range(request.params.range);
+
+    let is_definition = main_document.data.as_latex().map_or(false, |data| {
+        latex::SyntaxNode::new_root(data.green.clone())
+            .descendants()
+            .filter_map(latex::ColorDefinition::cast)
+            .any(|color| find_definition_range(&color) == Some(range))
+    });
+
+    if !is_definition {
+        return Vec::new();
+    }
+
+    let Color {
+        red, green, blue, ..
+    } = request.params.color;
+
+    let to_byte = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let presentations = [
+        (
+            "HTML",
+            format!(
+                "{:02X}{:02X}{:02X}",
+                to_byte(red),
+                to_byte(green),
+                to_byte(blue)
+            ),
+        ),
+        (
+            "rgb",
+            format!(
+                "{}, {}, {}",
+                format_number(red),
+                format_number(green),
+                format_number(blue)
+            ),
+        ),
+        (
+            "RGB",
+            format!("{}, {}, {}", to_byte(red), to_byte(green), to_byte(blue)),
+        ),
+    ];
+
+    presentations
+        .into_iter()
+        .map(|(model, spec)| {
+            let new_text = format!("{{{}}}{{{}}}", model, spec);
+            ColorPresentation {
+                label: new_text.clone(),
+                text_edit: Some(TextEdit::new(request.params.range, new_text)),
+                additional_text_edits: None,
+            }
+        })
+        .collect()
+}
+
+fn find_user_colors(workspace: &Workspace) -> FxHashMap<String, Rgb> {
+    let ordering = ProjectOrdering::from(workspace);
+    let mut documents: Vec<_> = workspace.documents_by_uri.values().collect();
+    documents.sort_by_cached_key(|document| ordering.get(&document.uri));
+
+    let definitions: Vec<_> = documents
+        .into_iter()
+        .filter_map(|document| document.data.as_latex())
+        .flat_map(|data| {
+            latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(latex::ColorDefinition::cast)
+        })
+        .filter_map(|color| {
+            let name = color.name()?.key()?.to_string();
+            Some((name, color))
+        })
+        .collect();
+
+    // Colors can be defined in terms of colors from documents that come later in the project,
+    // so the definitions are evaluated until no more colors can be resolved.
+    let mut values = vec![None; definitions.len()];
+    let mut user_colors = FxHashMap::default();
+    loop {
+        let mut changed = false;
+        for ((name, color), value) in definitions.iter().zip(&mut values) {
+            if value.is_none() {
+                if let Some(rgb) = eval_definition(&user_colors, color) {
+                    *value = Some(rgb);
+                    user_colors.insert(name.clone(), rgb);
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    definitions
+        .into_iter()
+        .zip(values)
+        .filter_map(|((name, _), value)| Some((name, value?)))
+        .collect()
+}
+
+fn find_reference_color(
+    user_colors: &FxHashMap<String, Rgb>,
+    color: &latex::ColorReference,
+) -> Option<(TextRange, Rgb)> {
+    let name = color.name()?.key()?;
+    let rgb = eval_expression(user_colors, &name.to_string())?;
+    Some((latex::small_range(&name), rgb))
+}
+
+fn find_definition_color(
+    user_colors: &FxHashMap<String, Rgb>,
+    color: &latex::ColorDefinition,
+) -> Option<(TextRange, Rgb)> {
+    let range = find_definition_range(color)?;
+    let rgb = eval_definition(user_colors, color)?;
+    Some((range, rgb))
+}
+
+fn find_definition_range(color: &latex::ColorDefinition) -> Option<TextRange> {
+    let model = color.model()?;
+    let spec = color.spec()?;
+    Some(TextRange::new(
+        model.syntax().text_range().start(),
+        latex::small_range(&spec).end(),
+    ))
+}
+
+fn eval_definition(
+    user_colors: &FxHashMap<String, Rgb>,
+    color: &latex::ColorDefinition,
+) -> Option<Rgb> {
+    let model = color.model()?.key()?.to_string();
+    let spec = color.spec()?.content_text()?;
+    eval_model(user_colors, &model, &spec)
+}
+
+fn eval_model(user_colors: &FxHashMap<String, Rgb>, model: &str, spec: &str) -> Option<Rgb> {
+    let values: Vec<_> = spec
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|value| !value.is_empty())
+        .collect();
+
+    let floats = || {
+        values
+            .iter()
+            .map(|value| value.parse::<f32>().ok())
+            .collect::<Option<Vec<_>>>()
+    };
+
+    match (model, values.as_slice()) {
+        ("rgb", [_, _, _]) => {
+            let rgb = floats()?;
+            Some([rgb[0], rgb[1], rgb[2]])
+        }
+        ("RGB", [_, _, _]) => {
+            let rgb = floats()?;
+            Some([rgb[0] / 255.0, rgb[1] / 255.0, rgb[2] / 255.0])
+        }
+        ("HTML", [hex]) => parse_hex(hex),
+        ("cmy", [_, _, _]) => {
+            let cmy = floats()?;
+            Some([1.0 - cmy[0], 1.0 - cmy[1], 1.0 - cmy[2]])
+        }
+        ("cmyk", [_, _, _, _]) => {
+            let cmyk = floats()?;
+            let convert = |value: f32| (1.0 - value) * (1.0 - cmyk[3]);
+            Some([convert(cmyk[0]), convert(cmyk[1]), convert(cmyk[2])])
+        }
+        ("gray", [_]) => {
+            let gray = floats()?[0];
+            Some([gray, gray, gray])
+        }
+        ("named", _) => eval_expression(user_colors, spec),
+        _ => None,
+    }
+    .map(|rgb| rgb.map(|value| value.clamp(0.0, 1.0)))
+}
+
+fn eval_expression(user_colors: &FxHashMap<String, Rgb>, expression: &str) -> Option<Rgb> {
+    let expression = expression.trim();
+    let (is_complement, expression) = match expression.strip_prefix('-') {
+        Some(expression) => (true, expression),
+        None => (false, expression),
+    };
+
+    let mut parts = expression.split('!');
+    let mut rgb = find_named_color(user_colors, parts.next()?)?;
+    while let Some(percent) = parts.next() {
+        let percent = percent.trim().parse::<f32>().ok()?.clamp(0.0, 100.0) / 100.0;
+        let other = match parts.next() {
+            Some(name) => find_named_color(user_colors, name)?,
+            None => WHITE,
+        };
+
+        for i in 0..3 {
+            rgb[i] = percent * rgb[i] + (1.0 - percent) * other[i];
+        }
+    }
+
+    if is_complement {
+        rgb = rgb.map(|value| 1.0 - value);
+    }
+
+    Some(rgb)
+}
+
+fn find_named_color(user_colors: &FxHashMap<String, Rgb>, name: &str) -> Option<Rgb> {
+    let name = name.trim();
+    user_colors.get(name).copied().or_else(|| {
+        LANGUAGE_DATA
+            .find_color(name)
+            .and_then(|color| parse_hex(&color.rgb))
+    })
+}
+
+fn parse_hex(hex: &str) -> Option<Rgb> {
+    if hex.len() != 6 {
+        return None;
+    }
+
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    Some([
+        ((value >> 16) & 0xFF) as f32 / 255.0,
+        ((value >> 8) & 0xFF) as f32 / 255.0,
+        (value & 0xFF) as f32 / 255.0,
+    ])
+}
+
+fn format_number(value: f32) -> String {
+    let text = format!("{:.3}", value.clamp(0.0, 1.0));
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
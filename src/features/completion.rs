@@ -7,10 +7,13 @@ mod color_model;
 mod component_command;
 mod component_environment;
 mod entry_type;
+mod environment_snippet;
 mod field;
+mod field_value;
 mod glossary_ref;
 mod import;
 mod include;
+mod key_value;
 mod label;
 mod theorem;
 mod tikz_library;
@@ -24,8 +27,8 @@ use std::borrow::Cow;
 use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 use itertools::Itertools;
 use lsp_types::{
-    CompletionItem, CompletionList, CompletionParams, CompletionTextEdit, Documentation,
-    InsertTextFormat, MarkupContent, MarkupKind, TextEdit,
+    CompletionItem, CompletionItemLabelDetails, CompletionList, CompletionParams,
+    CompletionTextEdit, Documentation, InsertTextFormat, MarkupContent, MarkupKind, TextEdit,
 };
 use rowan::{ast::AstNode, TextSize};
 use rustc_hash::FxHashSet;
@@ -48,17 +51,23 @@ use self::{
     component_command::complete_component_commands,
     component_environment::complete_component_environments,
     entry_type::complete_entry_types,
+    environment_snippet::complete_environment_snippets,
     field::complete_fields,
+    field_value::complete_field_values,
     glossary_ref::complete_glossary_entries,
     import::complete_imports,
     include::complete_includes,
+    key_value::complete_key_values,
     label::complete_labels,
     theorem::complete_theorem_environments,
     tikz_library::complete_tikz_libraries,
     types::{InternalCompletionItem, InternalCompletionItemData},
     user_command::complete_user_commands,
     user_environment::complete_user_environments,
-    util::{adjust_kind, component_detail, image_documentation},
+    util::{
+        adjust_kind, build_command_snippet, component_detail, image_documentation,
+        is_math_component, supports_label_details, supports_snippets,
+    },
 };
 
 pub use self::types::CompletionItemData;
@@ -78,6 +87,7 @@ pub fn complete(request: FeatureRequest<CompletionParams>) -> Option<CompletionL
     log::debug!("[Completion] Cursor: {:?}", context.cursor);
     complete_entry_types(&context, &mut items);
     complete_fields(&context, &mut items);
+    complete_field_values(&context, &mut items);
     complete_arguments(&context, &mut items);
     complete_citations(&context, &mut items);
     complete_imports(&context, &mut items);
@@ -86,6 +96,7 @@ pub fn complete(request: FeatureRequest<CompletionParams>) -> Option<CompletionL
     complete_acronyms(&context, &mut items);
     complete_glossary_entries(&context, &mut items);
     complete_includes(&context, &mut items);
+    complete_key_values(&context, &mut items);
     complete_labels(&context, &mut items);
     complete_tikz_libraries(&context, &mut items);
     complete_component_environments(&context, &mut items);
@@ -94,10 +105,12 @@ pub fn complete(request: FeatureRequest<CompletionParams>) -> Option<CompletionL
     complete_begin_command(&context, &mut items);
     complete_component_commands(&context, &mut items);
     complete_user_commands(&context, &mut items);
+    complete_environment_snippets(&context, &mut items);
 
     let mut items = dedup(items);
     preselect(&context, &mut items);
     score(&context, &mut items);
+    rank_math_commands(&context, &mut items);
 
     items.sort_by(|a, b| {
         a.preselect
@@ -198,13 +211,22 @@ fn score(context: &CursorContext<CompletionParams>, items: &mut Vec<InternalComp
             InternalCompletionItemData::Field { field } => {
                 matcher.fuzzy_match(&field.name, &pattern)
             }
+            InternalCompletionItemData::FieldValue { name, .. } => {
+                matcher.fuzzy_match(name, &pattern)
+            }
             InternalCompletionItemData::Argument { name, .. } => {
                 matcher.fuzzy_match(name, &pattern)
             }
             InternalCompletionItemData::BeginCommand => matcher.fuzzy_match("begin", &pattern[1..]),
-            InternalCompletionItemData::Citation { key, .. } => matcher.fuzzy_match(key, &pattern),
-            InternalCompletionItemData::ComponentCommand { name, .. } => {
-                matcher.fuzzy_match(name, &pattern[1..])
+            InternalCompletionItemData::Citation { text, .. } => pattern
+                .split_whitespace()
+                .map(|term| matcher.fuzzy_match(text, term))
+                .sum(),
+            InternalCompletionItemData::ComponentCommand { name, glyph, .. } => {
+                let pattern = pattern.strip_prefix('\\').unwrap_or(&pattern);
+                matcher
+                    .fuzzy_match(name, pattern)
+                    .or_else(|| glyph.and_then(|glyph| matcher.fuzzy_match(glyph, pattern)))
             }
             InternalCompletionItemData::ComponentEnvironment { name, .. } => {
                 matcher.fuzzy_match(name, &pattern)
@@ -221,17 +243,56 @@ fn score(context: &CursorContext<CompletionParams>, items: &mut Vec<InternalComp
             InternalCompletionItemData::Directory { name } => {
                 matcher.fuzzy_match(name, file_pattern)
             }
-            InternalCompletionItemData::Label { name, .. } => matcher.fuzzy_match(name, &pattern),
-            InternalCompletionItemData::UserCommand { name } => matcher.fuzzy_match(name, &pattern),
+            InternalCompletionItemData::Label { name, boost, .. } => matcher
+                .fuzzy_match(name, &pattern)
+                .map(|score| score + boost),
+            InternalCompletionItemData::UserCommand { name, .. } => {
+                matcher.fuzzy_match(name, &pattern)
+            }
             InternalCompletionItemData::UserEnvironment { name } => {
                 matcher.fuzzy_match(name, &pattern)
             }
             InternalCompletionItemData::PgfLibrary { name } => matcher.fuzzy_match(name, &pattern),
             InternalCompletionItemData::TikzLibrary { name } => matcher.fuzzy_match(name, &pattern),
+            InternalCompletionItemData::OptionKey { option } => {
+                matcher.fuzzy_match(&option.name, &pattern)
+            }
+            InternalCompletionItemData::OptionValue { name } => matcher.fuzzy_match(name, &pattern),
+            InternalCompletionItemData::EnvironmentSnippet { label, .. } => {
+                matcher.fuzzy_match(label, &pattern[1..])
+            }
         };
     }
 }
 
+fn rank_math_commands(
+    context: &CursorContext<CompletionParams>,
+    items: &mut [InternalCompletionItem],
+) {
+    const MATH_BOOST: i64 = 1000;
+
+    let math_mode = context.is_inside_math();
+    for item in items {
+        if let InternalCompletionItemData::ComponentCommand {
+            image,
+            glyph,
+            file_names,
+            ..
+        } = &item.data
+        {
+            if !is_math_component(file_names) {
+                continue;
+            }
+
+            if math_mode {
+                item.score = item.score.map(|score| score + MATH_BOOST);
+            } else if glyph.is_some() || image.is_some() {
+                item.score = None;
+            }
+        }
+    }
+}
+
 fn preselect(
     context: &CursorContext<CompletionParams>,
     items: &mut [InternalCompletionItem],
@@ -295,6 +356,16 @@ fn convert_internal_items(
                 ..CompletionItem::default()
             }
         }
+        InternalCompletionItemData::FieldValue { name, kind } => {
+            let text_edit = TextEdit::new(range, name.clone());
+            CompletionItem {
+                label: name,
+                kind: Some(adjust_kind(&context.request, kind.completion_kind())),
+                data: Some(serde_json::to_value(CompletionItemData::FieldValue).unwrap()),
+                text_edit: Some(CompletionTextEdit::Edit(text_edit)),
+                ..CompletionItem::default()
+            }
+        }
         InternalCompletionItemData::Argument { name, image } => {
             let text_edit = TextEdit::new(range, name.into());
             CompletionItem {
@@ -311,18 +382,7 @@ fn convert_internal_items(
             }
         }
         InternalCompletionItemData::BeginCommand => {
-            if context
-                .request
-                .workspace
-                .environment
-                .client_capabilities
-                .text_document
-                .as_ref()
-                .and_then(|cap| cap.completion.as_ref())
-                .and_then(|cap| cap.completion_item.as_ref())
-                .and_then(|cap| cap.snippet_support)
-                == Some(true)
-            {
+            if supports_snippets(&context.request) {
                 let text_edit = TextEdit::new(range, "begin{$1}\n\t$0\n\\end{$1}".into());
                 CompletionItem {
                     kind: Some(adjust_kind(
@@ -347,11 +407,24 @@ fn convert_internal_items(
                 }
             }
         }
-        InternalCompletionItemData::Citation { uri, key, text, ty } => {
+        InternalCompletionItemData::Citation {
+            uri,
+            key,
+            text,
+            detail,
+            ty,
+        } => {
             let text_edit = TextEdit::new(range, key.to_string());
+            let label_details = detail
+                .filter(|_| supports_label_details(&context.request))
+                .map(|detail| CompletionItemLabelDetails {
+                    detail: None,
+                    description: Some(detail),
+                });
             CompletionItem {
                 label: key.to_string(),
                 kind: Some(adjust_kind(&context.request, ty.completion_kind())),
+                label_details,
                 filter_text: Some(text.clone()),
                 sort_text: Some(text),
                 data: Some(
@@ -375,9 +448,31 @@ fn convert_internal_items(
                 || component_detail(file_names),
                 |glyph| format!("{}, {}", glyph, component_detail(file_names)),
             );
+            let label_details = glyph
+                .filter(|_| supports_label_details(&context.request))
+                .map(|glyph| CompletionItemLabelDetails {
+                    detail: Some(format!(" {}", glyph)),
+                    description: None,
+                });
             let documentation =
                 image.and_then(|img| image_documentation(&context.request, name, img));
-            let text_edit = TextEdit::new(range, name.to_string());
+
+            let new_text = if context.cursor.command_range(context.offset).is_some() {
+                name.to_string()
+            } else {
+                format!("\\{}", name)
+            };
+
+            let filter_text = glyph
+                .filter(|_| {
+                    context
+                        .cursor
+                        .as_latex()
+                        .map_or(false, |token| !token.text().is_ascii())
+                })
+                .map(ToString::to_string);
+
+            let text_edit = TextEdit::new(range, new_text);
             CompletionItem {
                 kind: Some(adjust_kind(
                     &context.request,
@@ -385,6 +480,8 @@ fn convert_internal_items(
                 )),
                 data: Some(serde_json::to_value(CompletionItemData::Command).unwrap()),
                 documentation,
+                label_details,
+                filter_text,
                 text_edit: Some(CompletionTextEdit::Edit(text_edit)),
                 ..CompletionItem::new_simple(name.to_string(), detail)
             }
@@ -509,15 +606,16 @@ fn convert_internal_items(
             name,
             kind,
             header,
-            footer,
+            documentation,
             text,
+            ..
         } => {
             let text_edit = TextEdit::new(range, name.to_string());
             CompletionItem {
                 label: name,
                 kind: Some(adjust_kind(&context.request, kind.completion_kind())),
                 detail: header,
-                documentation: footer.map(Documentation::String),
+                documentation: documentation.map(Documentation::String),
                 sort_text: Some(text.clone()),
                 filter_text: Some(text),
                 text_edit: Some(CompletionTextEdit::Edit(text_edit)),
@@ -525,18 +623,45 @@ fn convert_internal_items(
                 ..CompletionItem::default()
             }
         }
-        InternalCompletionItemData::UserCommand { name } => {
-            let detail = "user-defined".into();
+        InternalCompletionItemData::UserCommand {
+            name,
+            definition,
+            file_name,
+        } => {
+            let detail = file_name.unwrap_or("user-defined").into();
             let name = &name[1..];
-            let text_edit = TextEdit::new(range, name.to_string());
-            CompletionItem {
-                kind: Some(adjust_kind(
-                    &context.request,
-                    Structure::Command.completion_kind(),
-                )),
-                data: Some(serde_json::to_value(CompletionItemData::Command).unwrap()),
-                text_edit: Some(CompletionTextEdit::Edit(text_edit)),
-                ..CompletionItem::new_simple(name.into(), detail)
+            match definition
+                .filter(|definition| !definition.parameters.is_empty())
+                .filter(|_| supports_snippets(&context.request))
+            {
+                Some(definition) => {
+                    let text_edit =
+                        TextEdit::new(range, build_command_snippet(name, &definition.parameters));
+                    CompletionItem {
+                        kind: Some(adjust_kind(
+                            &context.request,
+                            Structure::Command.completion_kind(),
+                        )),
+                        data: Some(
+                            serde_json::to_value(CompletionItemData::CommandSnippet).unwrap(),
+                        ),
+                        text_edit: Some(CompletionTextEdit::Edit(text_edit)),
+                        insert_text_format: Some(InsertTextFormat::SNIPPET),
+                        ..CompletionItem::new_simple(name.into(), detail)
+                    }
+                }
+                None => {
+                    let text_edit = TextEdit::new(range, name.to_string());
+                    CompletionItem {
+                        kind: Some(adjust_kind(
+                            &context.request,
+                            Structure::Command.completion_kind(),
+                        )),
+                        data: Some(serde_json::to_value(CompletionItemData::Command).unwrap()),
+                        text_edit: Some(CompletionTextEdit::Edit(text_edit)),
+                        ..CompletionItem::new_simple(name.into(), detail)
+                    }
+                }
             }
         }
         InternalCompletionItemData::UserEnvironment { name } => {
@@ -578,6 +703,50 @@ fn convert_internal_items(
                 ..CompletionItem::default()
             }
         }
+        InternalCompletionItemData::OptionKey { option } => {
+            let text_edit = TextEdit::new(range, option.name.clone());
+            CompletionItem {
+                label: option.name.clone(),
+                kind: Some(adjust_kind(
+                    &context.request,
+                    Structure::Key.completion_kind(),
+                )),
+                detail: Some(option.values.join(" | ")).filter(|detail| !detail.is_empty()),
+                data: Some(serde_json::to_value(CompletionItemData::OptionKey).unwrap()),
+                text_edit: Some(CompletionTextEdit::Edit(text_edit)),
+                ..CompletionItem::default()
+            }
+        }
+        InternalCompletionItemData::EnvironmentSnippet {
+            label,
+            snippet,
+            detail,
+        } => {
+            let text_edit = TextEdit::new(range, snippet);
+            CompletionItem {
+                kind: Some(adjust_kind(
+                    &context.request,
+                    Structure::Snippet.completion_kind(),
+                )),
+                data: Some(serde_json::to_value(CompletionItemData::CommandSnippet).unwrap()),
+                text_edit: Some(CompletionTextEdit::Edit(text_edit)),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                ..CompletionItem::new_simple(label, detail)
+            }
+        }
+        InternalCompletionItemData::OptionValue { name } => {
+            let text_edit = TextEdit::new(range, name.into());
+            CompletionItem {
+                label: name.into(),
+                kind: Some(adjust_kind(
+                    &context.request,
+                    Structure::Argument.completion_kind(),
+                )),
+                data: Some(serde_json::to_value(CompletionItemData::OptionValue).unwrap()),
+                text_edit: Some(CompletionTextEdit::Edit(text_edit)),
+                ..CompletionItem::default()
+            }
+        }
     };
     new_item.preselect = Some(item.preselect);
     new_item
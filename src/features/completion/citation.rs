@@ -1,11 +1,10 @@
 use std::sync::Arc;
 
 use lsp_types::CompletionParams;
-use once_cell::sync::Lazy;
-use regex::Regex;
 use rowan::{ast::AstNode, TextRange};
 
 use crate::{
+    citation,
     features::{cursor::CursorContext, lsp_kinds::Structure},
     syntax::{
         bibtex::{self, HasName, HasType},
@@ -85,21 +84,9 @@ fn make_item<'a>(
             |ty| Structure::Entry(ty.category),
         );
 
-    let entry_code = entry.syntax().text().to_string();
-    let text = format!(
-        "{} {}",
-        key,
-        WHITESPACE_REGEX
-            .replace_all(
-                &entry_code
-                    .replace('{', " ")
-                    .replace('}', " ")
-                    .replace(',', " ")
-                    .replace('=', " "),
-                " "
-            )
-            .trim(),
-    );
+    let text = format!("{} {}", key, citation::render_search_text(entry))
+        .trim_end()
+        .to_string();
 
     Some(InternalCompletionItem::new(
         range,
@@ -107,9 +94,8 @@ fn make_item<'a>(
             uri: Arc::clone(&document.uri),
             key,
             text,
+            detail: citation::render_detail(entry),
             ty,
         },
     ))
 }
-
-static WHITESPACE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new("\\s+").unwrap());
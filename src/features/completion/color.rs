@@ -12,10 +12,10 @@ pub fn complete_colors<'a>(
     let (_, range, group) = context.find_curly_group_word()?;
     latex::ColorReference::cast(group.syntax().parent()?)?;
 
-    for name in &LANGUAGE_DATA.colors {
+    for color in &LANGUAGE_DATA.colors {
         items.push(InternalCompletionItem::new(
             range,
-            InternalCompletionItemData::Color { name },
+            InternalCompletionItemData::Color { name: &color.name },
         ));
     }
 
@@ -1,6 +1,7 @@
 use lsp_types::CompletionParams;
+use rowan::TextRange;
 
-use crate::{component_db::COMPONENT_DATABASE, features::cursor::CursorContext};
+use crate::{component_db::COMPONENT_DATABASE, features::cursor::CursorContext, syntax::latex};
 
 use super::types::{InternalCompletionItem, InternalCompletionItemData};
 
@@ -8,10 +9,17 @@ pub fn complete_component_commands<'a>(
     context: &'a CursorContext<CompletionParams>,
     items: &mut Vec<InternalCompletionItem<'a>>,
 ) -> Option<()> {
-    let range = context.cursor.command_range(context.offset)?;
+    let (range, glyphs_only) = match context.cursor.command_range(context.offset) {
+        Some(range) => (range, false),
+        None => (find_glyph_range(context)?, true),
+    };
 
     for component in COMPONENT_DATABASE.linked_components(&context.request.workspace) {
         for command in &component.commands {
+            if glyphs_only && command.glyph.is_none() {
+                continue;
+            }
+
             items.push(InternalCompletionItem::new(
                 range,
                 InternalCompletionItemData::ComponentCommand {
@@ -26,3 +34,17 @@ pub fn complete_component_commands<'a>(
 
     Some(())
 }
+
+fn find_glyph_range(context: &CursorContext<CompletionParams>) -> Option<TextRange> {
+    let token = context
+        .cursor
+        .as_latex()
+        .filter(|token| token.kind() == latex::WORD)
+        .filter(|token| !token.text().is_ascii())?;
+
+    if !context.is_inside_math() {
+        return None;
+    }
+
+    Some(token.text_range())
+}
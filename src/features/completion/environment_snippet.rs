@@ -0,0 +1,135 @@
+use lsp_types::CompletionParams;
+use rowan::TextRange;
+
+use crate::{
+    component_db::COMPONENT_DATABASE, features::cursor::CursorContext, EnvironmentParameter,
+    LANGUAGE_DATA,
+};
+
+use super::{
+    types::{InternalCompletionItem, InternalCompletionItemData},
+    util::{build_command_snippet, component_detail, escape_snippet, supports_snippets},
+};
+
+pub fn complete_environment_snippets<'a>(
+    context: &'a CursorContext<CompletionParams>,
+    items: &mut Vec<InternalCompletionItem<'a>>,
+) -> Option<()> {
+    if !supports_snippets(&context.request) {
+        return None;
+    }
+
+    let range = context.cursor.command_range(context.offset)?;
+
+    for component in COMPONENT_DATABASE.linked_components(&context.request.workspace) {
+        for name in &component.environments {
+            let snippet = if matches!(name.as_str(), "figure" | "figure*") {
+                build_figure_snippet(context, name)
+            } else {
+                let arguments = LANGUAGE_DATA
+                    .find_environment_parameters(name)
+                    .map(build_argument_snippet)
+                    .unwrap_or_default();
+
+                build_environment_snippet(name, &arguments, "$0")
+            };
+
+            push_snippet(
+                items,
+                range,
+                name,
+                snippet,
+                component_detail(&component.file_names),
+            );
+        }
+    }
+
+    for document in context.request.workspace.documents_by_uri.values() {
+        if let Some(data) = document.data.as_latex() {
+            for environment in &data.extras.user_environments {
+                let arguments = build_command_snippet("", &environment.parameters);
+                let snippet = build_environment_snippet(&environment.name, &arguments, "$0");
+                push_snippet(
+                    items,
+                    range,
+                    &environment.name,
+                    snippet,
+                    "user-defined".into(),
+                );
+            }
+
+            for environment in &data.extras.theorem_environments {
+                let snippet =
+                    build_environment_snippet(&environment.name, "${1:[${2:title}]}", "$0");
+                push_snippet(
+                    items,
+                    range,
+                    &environment.name,
+                    snippet,
+                    environment.description.clone(),
+                );
+            }
+        }
+    }
+
+    Some(())
+}
+
+fn push_snippet(
+    items: &mut Vec<InternalCompletionItem>,
+    range: TextRange,
+    name: &str,
+    snippet: String,
+    detail: String,
+) {
+    items.push(InternalCompletionItem::new(
+        range,
+        InternalCompletionItemData::EnvironmentSnippet {
+            label: format!("begin{{{}}}", name),
+            snippet,
+            detail,
+        },
+    ));
+}
+
+fn build_environment_snippet(name: &str, arguments: &str, body: &str) -> String {
+    format!(
+        "begin{{{name}}}{arguments}\n\t{body}\n\\end{{{name}}}",
+        name = escape_snippet(name),
+        arguments = arguments,
+        body = body,
+    )
+}
+
+fn build_argument_snippet(parameters: &[EnvironmentParameter]) -> String {
+    let mut snippet = String::new();
+    for (i, parameter) in parameters.iter().enumerate() {
+        let placeholder = format!("${{{}:{}}}", i + 1, escape_snippet(&parameter.name));
+        if parameter.optional {
+            snippet.push_str(&format!("[{}]", placeholder));
+        } else {
+            snippet.push_str(&format!("{{{}}}", placeholder));
+        }
+    }
+
+    snippet
+}
+
+fn build_figure_snippet(context: &CursorContext<CompletionParams>, name: &str) -> String {
+    let prefix = context
+        .request
+        .workspace
+        .environment
+        .options
+        .completion
+        .figure_label_prefix();
+
+    build_environment_snippet(
+        name,
+        "[${1:htbp}]",
+        &format!(
+            "\\centering\n\t$0\n\t\\caption{{$2}}\n\t\\label{{{}$3}}",
+            escape_snippet(prefix)
+        ),
+    )
+}
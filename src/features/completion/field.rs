@@ -7,7 +7,10 @@ use crate::{
     LANGUAGE_DATA,
 };
 
-use super::types::{InternalCompletionItem, InternalCompletionItemData};
+use super::{
+    field_value::find_field,
+    types::{InternalCompletionItem, InternalCompletionItemData},
+};
 
 pub fn complete_fields<'a>(
     context: &'a CursorContext<CompletionParams>,
@@ -21,6 +24,11 @@ pub fn complete_fields<'a>(
         TextRange::empty(context.offset)
     };
 
+    let entry = token.parent_ancestors().find_map(bibtex::Entry::cast)?;
+    if find_field(context, &entry).is_some() {
+        return None;
+    }
+
     let parent = token.parent()?;
     if let Some(entry) = bibtex::Entry::cast(parent.clone()) {
         if entry.name_token()?.text_range() == token.text_range() {
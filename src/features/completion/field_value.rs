@@ -0,0 +1,181 @@
+use lsp_types::CompletionParams;
+use rowan::{ast::AstNode, TextRange, TextSize};
+
+use crate::{
+    citation::field::text::TextFieldData,
+    features::{cursor::CursorContext, lsp_kinds::Structure},
+    syntax::bibtex::{self, HasComma, HasEq, HasName, HasType, HasValue},
+    BibtexEntryTypeCategory, LANGUAGE_DATA,
+};
+
+use super::types::{InternalCompletionItem, InternalCompletionItemData};
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+pub fn complete_field_values<'a>(
+    context: &'a CursorContext<CompletionParams>,
+    items: &mut Vec<InternalCompletionItem<'a>>,
+) -> Option<()> {
+    let token = context.cursor.as_bibtex()?;
+    let entry = token.parent_ancestors().find_map(bibtex::Entry::cast)?;
+    let field = find_field(context, &entry)?;
+    let name = field.name_token()?.text().to_lowercase();
+
+    let group = token
+        .parent_ancestors()
+        .take_while(|node| node != field.syntax())
+        .filter(|node| matches!(node.kind(), bibtex::CURLY_GROUP | bibtex::QUOTE_GROUP))
+        .last();
+
+    let range = match &group {
+        Some(group) => find_list_item_range(context, group)?,
+        None if matches!(token.kind(), bibtex::NAME | bibtex::INTEGER)
+            && token.text_range().contains_inclusive(context.offset) =>
+        {
+            token.text_range()
+        }
+        None => TextRange::empty(context.offset),
+    };
+
+    let mut push = |name: String, kind: Structure| {
+        let data = InternalCompletionItemData::FieldValue { name, kind };
+        items.push(InternalCompletionItem::new(range, data));
+    };
+
+    if group.is_none() {
+        for document in context.request.workspace.documents_by_uri.values() {
+            if let Some(data) = document.data.as_bibtex() {
+                bibtex::SyntaxNode::new_root(data.green.clone())
+                    .children()
+                    .filter_map(bibtex::StringDef::cast)
+                    .filter_map(|string| string.name_token())
+                    .for_each(|name| {
+                        push(
+                            name.to_string(),
+                            Structure::Entry(BibtexEntryTypeCategory::String),
+                        )
+                    });
+            }
+        }
+
+        if name == "month" {
+            for month in MONTHS {
+                push(month.into(), Structure::Argument);
+            }
+        }
+    }
+
+    match name.as_str() {
+        "crossref" | "xref" | "related" => {
+            let current_key = entry.name_token().map(|token| token.to_string());
+            for document in context.request.workspace.documents_by_uri.values() {
+                if let Some(data) = document.data.as_bibtex() {
+                    for other in bibtex::SyntaxNode::new_root(data.green.clone())
+                        .children()
+                        .filter_map(bibtex::Entry::cast)
+                    {
+                        let key = match other.name_token() {
+                            Some(key) if Some(key.to_string()) != current_key => key.to_string(),
+                            _ => continue,
+                        };
+
+                        let kind = other
+                            .type_token()
+                            .and_then(|ty| LANGUAGE_DATA.find_entry_type(&ty.text()[1..]))
+                            .map_or(Structure::Entry(BibtexEntryTypeCategory::Misc), |ty| {
+                                Structure::Entry(ty.category)
+                            });
+
+                        push(key, kind);
+                    }
+                }
+            }
+        }
+        "langid" | "language" => {
+            for language in &LANGUAGE_DATA.language_ids {
+                push(language.clone(), Structure::Argument);
+            }
+        }
+        "pubstate" => {
+            for state in &LANGUAGE_DATA.publication_states {
+                push(state.clone(), Structure::Argument);
+            }
+        }
+        "keywords" => {
+            for document in context.request.workspace.documents_by_uri.values() {
+                if let Some(data) = document.data.as_bibtex() {
+                    bibtex::SyntaxNode::new_root(data.green.clone())
+                        .descendants()
+                        .filter_map(bibtex::Field::cast)
+                        .filter(|other| other.syntax() != field.syntax())
+                        .filter(|other| {
+                            other
+                                .name_token()
+                                .map_or(false, |name| name.text().eq_ignore_ascii_case("keywords"))
+                        })
+                        .filter_map(|other| TextFieldData::parse(&other.value()?))
+                        .for_each(|data| {
+                            data.text
+                                .split(',')
+                                .map(str::trim)
+                                .filter(|keyword| !keyword.is_empty())
+                                .for_each(|keyword| push(keyword.into(), Structure::Argument));
+                        });
+                }
+            }
+        }
+        _ => {}
+    };
+
+    Some(())
+}
+
+pub fn find_field(
+    context: &CursorContext<CompletionParams>,
+    entry: &bibtex::Entry,
+) -> Option<bibtex::Field> {
+    entry.fields().find(|field| {
+        field
+            .syntax()
+            .text_range()
+            .contains_inclusive(context.offset)
+            && field
+                .eq_token()
+                .map_or(false, |eq| eq.text_range().end() <= context.offset)
+            && field
+                .comma_token()
+                .map_or(true, |comma| context.offset <= comma.text_range().start())
+    })
+}
+
+fn find_list_item_range(
+    context: &CursorContext<CompletionParams>,
+    group: &bibtex::SyntaxNode,
+) -> Option<TextRange> {
+    let text = group.text().to_string();
+    let group_start = group.text_range().start();
+    let cursor = usize::from(context.offset.checked_sub(group_start)?);
+    if cursor == 0 || cursor > text.len() {
+        return None;
+    }
+
+    let prefix = &text[1..cursor];
+    if cursor == text.len() && prefix.ends_with(['}', '"']) {
+        return None;
+    }
+
+    let word_start = prefix.rfind(',').map_or(0, |i| i + 1);
+    let word = &prefix[word_start..];
+    let start = 1 + word_start + (word.len() - word.trim_start().len());
+
+    let suffix = &text[cursor..];
+    let suffix = &suffix[..suffix.find([',', '}', '"']).unwrap_or(suffix.len())];
+    let end = cursor + suffix.trim_end().len();
+
+    Some(TextRange::new(
+        group_start + TextSize::try_from(start).ok()?,
+        group_start + TextSize::try_from(end).ok()?,
+    ))
+}
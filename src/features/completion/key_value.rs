@@ -0,0 +1,152 @@
+use lsp_types::CompletionParams;
+use rowan::{ast::AstNode, TextRange, TextSize};
+
+use crate::{features::cursor::CursorContext, syntax::latex, KeyValueOption, LANGUAGE_DATA};
+
+use super::types::{InternalCompletionItem, InternalCompletionItemData};
+
+pub fn complete_key_values<'a>(
+    context: &'a CursorContext<CompletionParams>,
+    items: &mut Vec<InternalCompletionItem<'a>>,
+) -> Option<()> {
+    let token = context.cursor.as_latex()?;
+    let (group, options) = token.parent_ancestors().find_map(|node| {
+        find_include_options(context, &node).or_else(|| find_tikz_options(context, &node))
+    })?;
+
+    let (key, range) = find_option_position(context, &group)?;
+    match key {
+        Some(key) => {
+            let option = options.into_iter().find(|option| option.name == key)?;
+            for name in &option.values {
+                items.push(InternalCompletionItem::new(
+                    range,
+                    InternalCompletionItemData::OptionValue { name },
+                ));
+            }
+        }
+        None => {
+            for option in options {
+                items.push(InternalCompletionItem::new(
+                    range,
+                    InternalCompletionItemData::OptionKey { option },
+                ));
+            }
+        }
+    };
+
+    Some(())
+}
+
+fn find_include_options(
+    context: &CursorContext<CompletionParams>,
+    node: &latex::SyntaxNode,
+) -> Option<(latex::SyntaxNode, Vec<&'static KeyValueOption>)> {
+    let group = latex::BrackGroupKeyValue::cast(node.clone())
+        .filter(|group| context.is_inside_latex_brack(group))?;
+
+    let include = latex::Include::cast(group.syntax().parent()?)?;
+    let extension = match include.syntax().kind() {
+        latex::GRAPHICS_INCLUDE if include.command()?.text() == "\\includegraphics" => {
+            let options = LANGUAGE_DATA.graphics_options.iter().collect();
+            return Some((group.syntax().clone(), options));
+        }
+        latex::PACKAGE_INCLUDE => "sty",
+        latex::CLASS_INCLUDE => "cls",
+        _ => return None,
+    };
+
+    let options = include
+        .path_list()?
+        .keys()
+        .filter_map(|name| {
+            LANGUAGE_DATA.find_package_options(&format!("{}.{}", name.to_string(), extension))
+        })
+        .flatten()
+        .collect();
+
+    Some((group.syntax().clone(), options))
+}
+
+fn find_tikz_options(
+    context: &CursorContext<CompletionParams>,
+    node: &latex::SyntaxNode,
+) -> Option<(latex::SyntaxNode, Vec<&'static KeyValueOption>)> {
+    let group = latex::BrackGroup::cast(node.clone())
+        .filter(|group| context.is_inside_latex_brack(group))?;
+
+    let begin = latex::Begin::cast(group.syntax().parent()?)?;
+    if begin.name()?.key()?.to_string() != "tikzpicture" {
+        return None;
+    }
+
+    let mut libraries = vec!["tikz".to_string()];
+    for document in context.request.workspace.documents_by_uri.values() {
+        if let Some(data) = document.data.as_latex() {
+            latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(latex::TikzLibraryImport::cast)
+                .filter(|import| {
+                    import
+                        .command()
+                        .map_or(false, |command| command.text() == "\\usetikzlibrary")
+                })
+                .filter_map(|import| import.name_list())
+                .flat_map(|list| list.keys())
+                .for_each(|name| libraries.push(name.to_string()));
+        }
+    }
+
+    let options = libraries
+        .iter()
+        .filter_map(|library| LANGUAGE_DATA.find_tikz_options(library))
+        .flatten()
+        .collect();
+
+    Some((group.syntax().clone(), options))
+}
+
+fn find_option_position(
+    context: &CursorContext<CompletionParams>,
+    group: &latex::SyntaxNode,
+) -> Option<(Option<String>, TextRange)> {
+    let text = group.text().to_string();
+    let group_start = group.text_range().start();
+    let cursor = usize::from(context.offset.checked_sub(group_start)?);
+    if cursor == 0 || cursor > text.len() {
+        return None;
+    }
+
+    let prefix = &text[1..cursor];
+    let mut depth = 0;
+    let mut segment_start = 0;
+    let mut equals = None;
+    for (i, c) in prefix.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                segment_start = i + 1;
+                equals = None;
+            }
+            '=' if depth == 0 && equals.is_none() => equals = Some(i),
+            _ => {}
+        };
+    }
+
+    let key = equals.map(|i| prefix[segment_start..i].trim().to_string());
+    let word_start = equals.map_or(segment_start, |i| i + 1);
+    let word = &prefix[word_start..];
+    let start = 1 + word_start + (word.len() - word.trim_start().len());
+
+    let suffix = &text[cursor..];
+    let suffix = &suffix[..suffix.find([',', '=', ']', '}']).unwrap_or(suffix.len())];
+    let end = cursor + suffix.trim_end().len();
+
+    let range = TextRange::new(
+        group_start + TextSize::try_from(start).ok()?,
+        group_start + TextSize::try_from(end).ok()?,
+    );
+
+    Some((key, range))
+}
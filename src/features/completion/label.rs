@@ -5,16 +5,24 @@ use crate::{
     features::{cursor::CursorContext, lsp_kinds::Structure},
     render_label,
     syntax::latex,
-    LabelledObject,
+    LabelledFloatKind, LabelledObject,
 };
 
 use super::types::{InternalCompletionItem, InternalCompletionItemData};
 
+const KIND_BOOST: i64 = 1000;
+const SECTION_BOOST: i64 = 500;
+
 pub fn complete_labels<'a>(
     context: &'a CursorContext<CompletionParams>,
     items: &mut Vec<InternalCompletionItem<'a>>,
 ) -> Option<()> {
-    let (range, is_math) = find_reference(context).or_else(|| find_reference_range(context))?;
+    let (range, command) = find_reference(context).or_else(|| find_reference_range(context))?;
+    let is_math = command == "\\eqref";
+    let is_typed = command.contains("cref")
+        || command == "\\autoref"
+        || find_preferred_object(&command).is_some();
+    let section_range = find_current_section(context);
 
     for document in context.request.workspace.documents_by_uri.values() {
         if let Some(data) = document.data.as_latex() {
@@ -22,11 +30,17 @@ pub fn complete_labels<'a>(
                 .descendants()
                 .filter_map(latex::LabelDefinition::cast)
             {
+                let in_section = document.uri == context.request.uri
+                    && section_range.map_or(false, |range| {
+                        range.contains_range(latex::small_range(&label))
+                    });
+
                 if let Some(name) = label
                     .name()
                     .and_then(|name| name.key())
                     .map(|name| name.to_string())
                 {
+                    let mut boost = if in_section { SECTION_BOOST } else { 0 };
                     match render_label(&context.request.workspace, &name, Some(label)) {
                         Some(rendered_label) => {
                             let kind = match &rendered_label.object {
@@ -41,13 +55,20 @@ pub fn complete_labels<'a>(
                                 continue;
                             }
 
-                            let header = rendered_label.detail();
-                            let footer = match &rendered_label.object {
-                                LabelledObject::Float { caption, .. } => Some(caption.clone()),
-                                _ => None,
+                            if find_preferred_object(&command)
+                                .map_or(false, |matches| matches(&rendered_label.object))
+                            {
+                                boost += KIND_BOOST;
+                            }
+
+                            let reference = rendered_label.reference();
+                            let header = if is_typed {
+                                Some(reference.clone())
+                            } else {
+                                rendered_label.detail()
                             };
 
-                            let text = format!("{} {}", name, rendered_label.reference());
+                            let text = format!("{} {}", name, reference);
 
                             let item = InternalCompletionItem::new(
                                 range,
@@ -55,8 +76,9 @@ pub fn complete_labels<'a>(
                                     name,
                                     kind,
                                     header,
-                                    footer,
+                                    documentation: Some(reference),
                                     text,
+                                    boost,
                                 },
                             );
                             items.push(item);
@@ -64,7 +86,7 @@ pub fn complete_labels<'a>(
                         None => {
                             let kind = Structure::Label;
                             let header = None;
-                            let footer = None;
+                            let documentation = None;
                             let text = name.to_string();
                             let item = InternalCompletionItem::new(
                                 range,
@@ -72,8 +94,9 @@ pub fn complete_labels<'a>(
                                     name,
                                     kind,
                                     header,
-                                    footer,
+                                    documentation,
                                     text,
+                                    boost,
                                 },
                             );
                             items.push(item);
@@ -87,15 +110,51 @@ pub fn complete_labels<'a>(
     Some(())
 }
 
-fn find_reference(context: &CursorContext<CompletionParams>) -> Option<(TextRange, bool)> {
+fn find_reference(context: &CursorContext<CompletionParams>) -> Option<(TextRange, String)> {
     let (_, range, group) = context.find_curly_group_word_list()?;
     let reference = latex::LabelReference::cast(group.syntax().parent()?)?;
-    let is_math = reference.command()?.text() == "\\eqref";
-    Some((range, is_math))
+    let command = reference.command()?.text().to_string();
+    Some((range, command))
 }
 
-fn find_reference_range(context: &CursorContext<CompletionParams>) -> Option<(TextRange, bool)> {
+fn find_reference_range(context: &CursorContext<CompletionParams>) -> Option<(TextRange, String)> {
     let (_, range, group) = context.find_curly_group_word()?;
-    latex::LabelReferenceRange::cast(group.syntax().parent()?)?;
-    Some((range, false))
+    let reference = latex::LabelReferenceRange::cast(group.syntax().parent()?)?;
+    let command = reference.command()?.text().to_string();
+    Some((range, command))
+}
+
+fn find_current_section(context: &CursorContext<CompletionParams>) -> Option<TextRange> {
+    let section = context
+        .cursor
+        .as_latex()?
+        .parent_ancestors()
+        .find_map(latex::Section::cast)?;
+
+    Some(section.syntax().text_range())
+}
+
+fn find_preferred_object(command: &str) -> Option<fn(&LabelledObject) -> bool> {
+    let name = command.strip_prefix('\\').unwrap_or(command).to_lowercase();
+    if name.starts_with("eq") {
+        Some(|object| matches!(object, LabelledObject::Equation))
+    } else if name.starts_with("fig") {
+        Some(|object| is_float(object, LabelledFloatKind::Figure))
+    } else if name.starts_with("tab") {
+        Some(|object| is_float(object, LabelledFloatKind::Table))
+    } else if name.starts_with("lst") || name.starts_with("listing") {
+        Some(|object| is_float(object, LabelledFloatKind::Listing))
+    } else if name.starts_with("alg") {
+        Some(|object| is_float(object, LabelledFloatKind::Algorithm))
+    } else if name.starts_with("sec") || name.starts_with("chap") {
+        Some(|object| matches!(object, LabelledObject::Section { .. }))
+    } else if name.starts_with("thm") || name.starts_with("lem") {
+        Some(|object| matches!(object, LabelledObject::Theorem { .. }))
+    } else {
+        None
+    }
+}
+
+fn is_float(object: &LabelledObject, expected: LabelledFloatKind) -> bool {
+    matches!(object, LabelledObject::Float { kind, .. } if *kind == expected)
 }
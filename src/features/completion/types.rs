@@ -5,7 +5,10 @@ use rowan::TextRange;
 use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 
-use crate::{features::lsp_kinds::Structure, BibtexEntryTypeDoc, BibtexFieldDoc};
+use crate::{
+    features::lsp_kinds::Structure, syntax::latex, BibtexEntryTypeDoc, BibtexFieldDoc,
+    KeyValueOption,
+};
 
 #[derive(Debug, Clone)]
 pub struct InternalCompletionItem<'a> {
@@ -34,6 +37,10 @@ pub enum InternalCompletionItemData<'a> {
     Field {
         field: &'a BibtexFieldDoc,
     },
+    FieldValue {
+        name: String,
+        kind: Structure,
+    },
     Argument {
         name: &'a str,
         image: Option<&'a str>,
@@ -43,6 +50,7 @@ pub enum InternalCompletionItemData<'a> {
         uri: Arc<Url>,
         key: String,
         text: String,
+        detail: Option<String>,
         ty: Structure,
     },
     ComponentCommand {
@@ -83,11 +91,14 @@ pub enum InternalCompletionItemData<'a> {
         name: String,
         kind: Structure,
         header: Option<String>,
-        footer: Option<String>,
+        documentation: Option<String>,
         text: String,
+        boost: i64,
     },
     UserCommand {
         name: SmolStr,
+        definition: Option<&'a latex::UserCommand>,
+        file_name: Option<&'a str>,
     },
     UserEnvironment {
         name: String,
@@ -98,6 +109,17 @@ pub enum InternalCompletionItemData<'a> {
     TikzLibrary {
         name: &'a str,
     },
+    OptionKey {
+        option: &'a KeyValueOption,
+    },
+    OptionValue {
+        name: &'a str,
+    },
+    EnvironmentSnippet {
+        label: String,
+        snippet: String,
+        detail: String,
+    },
 }
 
 impl<'a> InternalCompletionItemData<'a> {
@@ -105,6 +127,7 @@ impl<'a> InternalCompletionItemData<'a> {
         match self {
             Self::EntryType { ty } => &ty.name,
             Self::Field { field } => &field.name,
+            Self::FieldValue { name, .. } => name,
             Self::Argument { name, .. } => name,
             Self::BeginCommand => "begin",
             Self::Citation { key, .. } => key,
@@ -119,10 +142,13 @@ impl<'a> InternalCompletionItemData<'a> {
             Self::File { name } => name,
             Self::Directory { name } => name,
             Self::Label { name, .. } => name,
-            Self::UserCommand { name } => name,
+            Self::UserCommand { name, .. } => name,
             Self::UserEnvironment { name } => name,
             Self::PgfLibrary { name } => name,
             Self::TikzLibrary { name } => name,
+            Self::OptionKey { option } => &option.name,
+            Self::OptionValue { name } => name,
+            Self::EnvironmentSnippet { label, .. } => label,
         }
     }
 }
@@ -144,8 +170,11 @@ pub enum CompletionItemData {
     Class,
     EntryType,
     FieldName,
+    FieldValue,
     Citation { uri: Url, key: SmolStr },
     Argument,
     Acronym,
     GlossaryEntry,
+    OptionKey,
+    OptionValue,
 }
@@ -11,6 +11,29 @@ pub fn complete_user_commands<'a>(
     let range = context.cursor.command_range(context.offset)?;
     let token = context.cursor.as_latex()?;
 
+    for (uri, document) in &context.request.workspace.documents_by_uri {
+        if let Some(data) = document.data.as_latex() {
+            let file_name = uri
+                .path_segments()
+                .and_then(|mut segments| segments.next_back());
+            for definition in data
+                .extras
+                .user_commands
+                .iter()
+                .filter(|definition| definition.name.as_str() != token.text())
+            {
+                items.push(InternalCompletionItem::new(
+                    range,
+                    InternalCompletionItemData::UserCommand {
+                        name: definition.name.clone(),
+                        definition: Some(definition),
+                        file_name,
+                    },
+                ));
+            }
+        }
+    }
+
     for document in context.request.workspace.documents_by_uri.values() {
         if let Some(data) = document.data.as_latex() {
             for name in data
@@ -22,7 +45,11 @@ pub fn complete_user_commands<'a>(
             {
                 items.push(InternalCompletionItem::new(
                     range,
-                    InternalCompletionItemData::UserCommand { name },
+                    InternalCompletionItemData::UserCommand {
+                        name,
+                        definition: None,
+                        file_name: None,
+                    },
                 ));
             }
         }
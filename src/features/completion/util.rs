@@ -1,7 +1,7 @@
 use lsp_types::{CompletionItemKind, CompletionParams, Documentation, MarkupContent, MarkupKind};
 use smol_str::SmolStr;
 
-use crate::features::FeatureRequest;
+use crate::{features::FeatureRequest, syntax::latex, LANGUAGE_DATA};
 
 pub fn component_detail(file_names: &[SmolStr]) -> String {
     if file_names.is_empty() {
@@ -11,6 +11,67 @@ pub fn component_detail(file_names: &[SmolStr]) -> String {
     }
 }
 
+pub fn is_math_component(file_names: &[SmolStr]) -> bool {
+    file_names.iter().any(|file_name| {
+        LANGUAGE_DATA
+            .math_components
+            .iter()
+            .any(|name| name == file_name)
+    })
+}
+
+pub fn build_command_snippet(name: &str, parameters: &[latex::UserParameter]) -> String {
+    let mut snippet = name.to_string();
+    let mut index = 0;
+    for param in parameters {
+        match (param.kind, &param.default) {
+            (latex::UserParameterKind::Mandatory, _) => {
+                index += 1;
+                snippet.push_str(&format!("{{${{{}}}}}", index));
+            }
+            (latex::UserParameterKind::Optional, Some(default)) => {
+                index += 1;
+                snippet.push_str(&format!("[${{{}:{}}}]", index, escape_snippet(default)));
+            }
+            (latex::UserParameterKind::Optional, None) => {}
+        };
+    }
+
+    snippet
+}
+
+pub fn escape_snippet(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('$', "\\$")
+        .replace('}', "\\}")
+}
+
+pub fn supports_snippets(request: &FeatureRequest<CompletionParams>) -> bool {
+    request
+        .workspace
+        .environment
+        .client_capabilities
+        .text_document
+        .as_ref()
+        .and_then(|cap| cap.completion.as_ref())
+        .and_then(|cap| cap.completion_item.as_ref())
+        .and_then(|cap| cap.snippet_support)
+        == Some(true)
+}
+
+pub fn supports_label_details(request: &FeatureRequest<CompletionParams>) -> bool {
+    request
+        .workspace
+        .environment
+        .client_capabilities
+        .text_document
+        .as_ref()
+        .and_then(|cap| cap.completion.as_ref())
+        .and_then(|cap| cap.completion_item.as_ref())
+        .and_then(|cap| cap.label_details_support)
+        == Some(true)
+}
+
 pub fn image_documentation(
     request: &FeatureRequest<CompletionParams>,
     name: &str,
@@ -1,12 +1,13 @@
 use lsp_types::{
-    CompletionParams, DocumentHighlightParams, GotoDefinitionParams, HoverParams, Position,
-    ReferenceParams, RenameParams, TextDocumentPositionParams,
+    CompletionParams, DocumentHighlightParams, GotoDefinitionParams, HoverParams,
+    LinkedEditingRangeParams, Position, ReferenceParams, RenameParams, SignatureHelpParams,
+    TextDocumentPositionParams,
 };
 use rowan::{ast::AstNode, TextRange, TextSize};
 
 use crate::{
     syntax::{bibtex, latex},
-    DocumentData, LineIndexExt,
+    DocumentData, LineIndexExt, LANGUAGE_DATA,
 };
 
 use super::FeatureRequest;
@@ -163,6 +164,58 @@ impl<P: HasPosition> CursorContext<P> {
         latex::small_range(group).contains(self.offset) || group.right_curly().is_none()
     }
 
+    pub fn is_inside_latex_brack(&self, group: &impl latex::HasBrack) -> bool {
+        latex::small_range(group).contains(self.offset) || group.right_brack().is_none()
+    }
+
+    pub fn is_inside_math(&self) -> bool {
+        const TEXT_COMMANDS: &[&str] = &[
+            "\\text",
+            "\\textrm",
+            "\\textbf",
+            "\\textit",
+            "\\mbox",
+            "\\intertext",
+        ];
+
+        let token = match self.cursor.as_latex() {
+            Some(token) => token,
+            None => return false,
+        };
+
+        for node in token.parent_ancestors() {
+            match node.kind() {
+                latex::FORMULA | latex::EQUATION => return true,
+                latex::ENVIRONMENT => {
+                    let name = latex::Environment::cast(node)
+                        .and_then(|env| env.begin())
+                        .and_then(|begin| begin.name())
+                        .and_then(|name| name.key())
+                        .map(|name| name.to_string());
+
+                    if name.map_or(false, |name| {
+                        LANGUAGE_DATA.math_environments.contains(&name)
+                    }) {
+                        return true;
+                    }
+                }
+                latex::CURLY_GROUP => {
+                    let command = node
+                        .parent()
+                        .and_then(latex::GenericCommand::cast)
+                        .and_then(|command| command.name());
+
+                    if command.map_or(false, |name| TEXT_COMMANDS.contains(&name.text())) {
+                        return false;
+                    }
+                }
+                _ => {}
+            };
+        }
+
+        false
+    }
+
     pub fn find_citation_key_word(&self) -> Option<(String, TextRange)> {
         let word = self
             .cursor
@@ -238,6 +291,38 @@ impl<P: HasPosition> CursorContext<P> {
         Some((name, range))
     }
 
+    pub fn find_environment_definition(&self) -> Option<(String, TextRange)> {
+        let (name, range, group) = self.find_curly_group_word()?;
+
+        if !matches!(
+            group.syntax().parent()?.kind(),
+            latex::ENVIRONMENT_DEFINITION | latex::THEOREM_DEFINITION
+        ) {
+            return None;
+        }
+
+        Some((name, range))
+    }
+
+    pub fn find_glossary_name(&self) -> Option<(String, TextRange)> {
+        let (name, range, group) = self.find_curly_group_word()?;
+
+        if name.is_empty()
+            || !matches!(
+                group.syntax().parent()?.kind(),
+                latex::GLOSSARY_ENTRY_DEFINITION
+                    | latex::GLOSSARY_ENTRY_REFERENCE
+                    | latex::ACRONYM_DEFINITION
+                    | latex::ACRONYM_DECLARATION
+                    | latex::ACRONYM_REFERENCE
+            )
+        {
+            return None;
+        }
+
+        Some((name, range))
+    }
+
     pub fn find_curly_group_word(&self) -> Option<(String, TextRange, latex::CurlyGroupWord)> {
         let token = self.cursor.as_latex()?;
         let key = latex::Key::cast(token.parent()?);
@@ -331,3 +416,15 @@ impl HasPosition for DocumentHighlightParams {
         self.text_document_position_params.position
     }
 }
+
+impl HasPosition for SignatureHelpParams {
+    fn position(&self) -> Position {
+        self.text_document_position_params.position
+    }
+}
+
+impl HasPosition for LinkedEditingRangeParams {
+    fn position(&self) -> Position {
+        self.text_document_position_params.position
+    }
+}
@@ -1,6 +1,8 @@
 mod command;
 mod document;
 mod entry;
+mod environment;
+mod glossary;
 mod label;
 mod string;
 
@@ -13,7 +15,9 @@ use crate::LineIndexExt;
 
 use self::{
     command::goto_command_definition, document::goto_document_definition,
-    entry::goto_entry_definition, label::goto_label_definition, string::goto_string_definition,
+    entry::goto_entry_definition, environment::goto_environment_definition,
+    glossary::goto_glossary_definition, label::goto_label_definition,
+    string::goto_string_definition,
 };
 
 use super::{cursor::CursorContext, FeatureRequest};
@@ -28,6 +32,8 @@ pub fn goto_definition(
     let links: Vec<_> = goto_command_definition(&context)
         .or_else(|| goto_document_definition(&context))
         .or_else(|| goto_entry_definition(&context))
+        .or_else(|| goto_environment_definition(&context))
+        .or_else(|| goto_glossary_definition(&context))
         .or_else(|| goto_label_definition(&context))
         .or_else(|| goto_string_definition(&context))?
         .into_iter()
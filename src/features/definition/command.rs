@@ -25,8 +25,7 @@ pub(super) fn goto_command_definition(
                 .descendants()
                 .filter_map(latex::CommandDefinition::cast)
                 .filter(|def| {
-                    def.name()
-                        .and_then(|name| name.command())
+                    def.defined_command()
                         .map_or(false, |node| node.text() == name.text())
                 })
                 .find_map(|def| {
@@ -34,7 +33,7 @@ pub(super) fn goto_command_definition(
                         origin_selection_range,
                         target_uri: Arc::clone(&document.uri),
                         target_range: latex::small_range(&def),
-                        target_selection_range: def.name()?.command()?.text_range(),
+                        target_selection_range: def.defined_command()?.text_range(),
                     })
                 })
             {
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use lsp_types::GotoDefinitionParams;
+use rowan::ast::AstNode;
+
+use crate::{features::cursor::CursorContext, syntax::latex};
+
+use super::DefinitionResult;
+
+pub(super) fn goto_environment_definition(
+    context: &CursorContext<GotoDefinitionParams>,
+) -> Option<Vec<DefinitionResult>> {
+    let (name_text, origin_selection_range) = context.find_environment_name()?;
+
+    for document in context.request.workspace.documents_by_uri.values() {
+        if let Some(data) = document.data.as_latex() {
+            for node in latex::SyntaxNode::new_root(data.green.clone()).descendants() {
+                if let Some((target_range, name)) = latex::EnvironmentDefinition::cast(node.clone())
+                    .and_then(|def| Some((latex::small_range(&def), def.name()?.key()?)))
+                    .or_else(|| {
+                        let def = latex::TheoremDefinition::cast(node)?;
+                        Some((latex::small_range(&def), def.name()?.key()?))
+                    })
+                    .filter(|(_, name)| name.to_string() == name_text)
+                {
+                    return Some(vec![DefinitionResult {
+                        origin_selection_range,
+                        target_uri: Arc::clone(&document.uri),
+                        target_range,
+                        target_selection_range: latex::small_range(&name),
+                    }]);
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,19 @@
+use lsp_types::GotoDefinitionParams;
+
+use crate::{features::cursor::CursorContext, find_glossary_definition};
+
+use super::DefinitionResult;
+
+pub(super) fn goto_glossary_definition(
+    context: &CursorContext<GotoDefinitionParams>,
+) -> Option<Vec<DefinitionResult>> {
+    let (name_text, origin_selection_range) = context.find_glossary_name()?;
+    let definition = find_glossary_definition(&context.request.workspace, &name_text)?;
+
+    Some(vec![DefinitionResult {
+        origin_selection_range,
+        target_uri: definition.uri,
+        target_range: definition.range,
+        target_selection_range: definition.name_range,
+    }])
+}
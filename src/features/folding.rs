@@ -1,9 +1,10 @@
 use lsp_types::{FoldingRange, FoldingRangeKind, FoldingRangeParams, Range};
-use rowan::ast::AstNode;
+use rowan::{ast::AstNode, NodeOrToken, TextRange, TextSize};
+use rustc_hash::FxHashSet;
 
 use crate::{
     syntax::{bibtex, latex},
-    DocumentData, LineIndexExt,
+    DocumentData, LineIndex, LineIndexExt,
 };
 
 use super::FeatureRequest;
@@ -13,22 +14,42 @@ pub fn find_foldings(request: FeatureRequest<FoldingRangeParams>) -> Vec<Folding
     let main_document = request.main_document();
     match &main_document.data {
         DocumentData::Latex(data) => {
-            for node in latex::SyntaxNode::new_root(data.green.clone()).descendants() {
+            let root = latex::SyntaxNode::new_root(data.green.clone());
+            for node in root.descendants() {
                 if let Some(folding) = latex::Environment::cast(node.clone())
                     .map(|node| latex::small_range(&node))
                     .or_else(|| {
                         latex::Section::cast(node.clone()).map(|node| latex::small_range(&node))
                     })
-                    .or_else(|| latex::EnumItem::cast(node).map(|node| latex::small_range(&node)))
+                    .or_else(|| {
+                        latex::EnumItem::cast(node.clone()).map(|node| latex::small_range(&node))
+                    })
                     .map(|node| main_document.line_index.line_col_lsp_range(node))
                     .map(create_range)
                 {
-                    foldings.push(folding);
+                    let kind = if is_comment_environment(&node) {
+                        FoldingRangeKind::Comment
+                    } else {
+                        FoldingRangeKind::Region
+                    };
+
+                    foldings.push(FoldingRange {
+                        kind: Some(kind),
+                        ..folding
+                    });
                 }
             }
+
+            let mut ranges = Vec::new();
+            find_latex_preamble(&root, &mut ranges);
+            find_latex_arguments(&root, &mut ranges);
+            find_latex_comments(&main_document.text, &root, &mut ranges);
+            find_latex_conditionals(&root, &mut ranges);
+            push_multiline(&main_document.line_index, ranges, &mut foldings);
         }
         DocumentData::Bibtex(data) => {
-            for node in bibtex::SyntaxNode::new_root(data.green.clone()).descendants() {
+            let root = bibtex::SyntaxNode::new_root(data.green.clone());
+            for node in root.descendants() {
                 if matches!(
                     node.kind(),
                     bibtex::PREAMBLE | bibtex::STRING | bibtex::ENTRY
@@ -40,6 +61,10 @@ pub fn find_foldings(request: FeatureRequest<FoldingRangeParams>) -> Vec<Folding
                     ));
                 }
             }
+
+            let mut ranges = Vec::new();
+            find_bibtex_comments(&root, &mut ranges);
+            push_multiline(&main_document.line_index, ranges, &mut foldings);
         }
         DocumentData::BuildLog(_) => {}
     }
@@ -55,3 +80,337 @@ fn create_range(range: Range) -> FoldingRange {
         kind: Some(FoldingRangeKind::Region),
     }
 }
+
+fn push_multiline(
+    line_index: &LineIndex,
+    ranges: Vec<(TextRange, FoldingRangeKind)>,
+    foldings: &mut Vec<FoldingRange>,
+) {
+    for (range, kind) in ranges {
+        let folding = create_range(line_index.line_col_lsp_range(range));
+        if folding.start_line < folding.end_line {
+            foldings.push(FoldingRange {
+                kind: Some(kind),
+                ..folding
+            });
+        }
+    }
+}
+
+fn is_comment_environment(node: &latex::SyntaxNode) -> bool {
+    latex::Environment::cast(node.clone())
+        .and_then(|env| env.begin())
+        .and_then(|begin| begin.name())
+        .and_then(|name| name.key())
+        .map_or(false, |name| name.to_string() == "comment")
+}
+
+fn find_latex_preamble(
+    root: &latex::SyntaxNode,
+    ranges: &mut Vec<(TextRange, FoldingRangeKind)>,
+) -> Option<()> {
+    let preamble = root
+        .children()
+        .find(|node| node.kind() == latex::PREAMBLE)?;
+    let document = preamble.children().find(|node| {
+        latex::Environment::cast(node.clone())
+            .and_then(|env| env.begin())
+            .and_then(|begin| begin.name())
+            .and_then(|name| name.key())
+            .map_or(false, |name| name.to_string() == "document")
+    })?;
+
+    let is_trivia = |token: &latex::SyntaxToken| {
+        matches!(
+            token.kind(),
+            latex::WHITESPACE | latex::LINE_BREAK | latex::COMMENT
+        )
+    };
+
+    let start = preamble
+        .descendants_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .take_while(|token| token.text_range().end() <= document.text_range().start())
+        .find(|token| !is_trivia(token))?;
+
+    let mut end = document.first_token()?.prev_token()?;
+    while is_trivia(&end) {
+        end = end.prev_token()?;
+    }
+
+    let range = TextRange::new(start.text_range().start(), end.text_range().end());
+    ranges.push((range, FoldingRangeKind::Imports));
+    Some(())
+}
+
+fn find_latex_arguments(root: &latex::SyntaxNode, ranges: &mut Vec<(TextRange, FoldingRangeKind)>) {
+    for group in root.descendants().filter(|node| {
+        matches!(
+            node.kind(),
+            latex::CURLY_GROUP
+                | latex::CURLY_GROUP_WORD
+                | latex::CURLY_GROUP_WORD_LIST
+                | latex::CURLY_GROUP_COMMAND
+                | latex::CURLY_GROUP_KEY_VALUE
+        )
+    }) {
+        let is_argument = group.parent().map_or(false, |parent| {
+            !matches!(
+                parent.kind(),
+                latex::ROOT
+                    | latex::PREAMBLE
+                    | latex::ENVIRONMENT
+                    | latex::EQUATION
+                    | latex::ENUM_ITEM
+                    | latex::PART
+                    | latex::CHAPTER
+                    | latex::SECTION
+                    | latex::SUBSECTION
+                    | latex::SUBSUBSECTION
+                    | latex::PARAGRAPH
+                    | latex::SUBPARAGRAPH
+            ) && parent
+                .first_token()
+                .map_or(false, |token| token.text().starts_with('\\'))
+        });
+
+        let end = group
+            .children_with_tokens()
+            .filter_map(NodeOrToken::into_token)
+            .find(|token| token.kind() == latex::R_CURLY);
+
+        if let Some(end) = end.filter(|_| is_argument) {
+            let range = TextRange::new(group.text_range().start(), end.text_range().end());
+            ranges.push((range, FoldingRangeKind::Region));
+        }
+    }
+}
+
+fn find_latex_comments(
+    text: &str,
+    root: &latex::SyntaxNode,
+    ranges: &mut Vec<(TextRange, FoldingRangeKind)>,
+) {
+    let mut regions = Vec::new();
+    let mut block: Option<TextRange> = None;
+    for token in root
+        .descendants_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .filter(|token| token.kind() == latex::COMMENT)
+    {
+        let range = token.text_range();
+        let line_start = text[..usize::from(range.start())]
+            .rfind('\n')
+            .map_or(0, |i| i + 1);
+
+        if !text[line_start..usize::from(range.start())]
+            .trim()
+            .is_empty()
+        {
+            continue;
+        }
+
+        if let Some(is_start) = find_region_marker(token.text()) {
+            ranges.extend(block.take().map(|range| (range, FoldingRangeKind::Comment)));
+            if is_start {
+                regions.push(range);
+            } else if let Some(start) = regions.pop() {
+                ranges.push((start.cover(range), FoldingRangeKind::Region));
+            }
+
+            continue;
+        }
+
+        block = match block {
+            Some(previous)
+                if text[usize::from(previous.end())..usize::from(range.start())]
+                    .matches('\n')
+                    .count()
+                    == 1 =>
+            {
+                Some(previous.cover(range))
+            }
+            Some(previous) => {
+                ranges.push((previous, FoldingRangeKind::Comment));
+                Some(range)
+            }
+            None => Some(range),
+        };
+    }
+
+    ranges.extend(block.map(|range| (range, FoldingRangeKind::Comment)));
+}
+
+const TEX_CONDITIONALS: &[&str] = &[
+    "\\if",
+    "\\ifcat",
+    "\\ifnum",
+    "\\ifdim",
+    "\\ifodd",
+    "\\ifvmode",
+    "\\ifhmode",
+    "\\ifmmode",
+    "\\ifinner",
+    "\\ifvoid",
+    "\\ifhbox",
+    "\\ifvbox",
+    "\\ifx",
+    "\\ifeof",
+    "\\iftrue",
+    "\\iffalse",
+    "\\ifcase",
+    "\\ifdefined",
+    "\\ifcsname",
+    "\\iffontchar",
+];
+
+fn find_latex_conditionals(
+    root: &latex::SyntaxNode,
+    ranges: &mut Vec<(TextRange, FoldingRangeKind)>,
+) {
+    for node in root
+        .descendants()
+        .filter(|node| node.kind() == latex::BLOCK_COMMENT)
+    {
+        ranges.push((node.text_range(), FoldingRangeKind::Comment));
+    }
+
+    let tokens: Vec<_> = root
+        .descendants_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .filter(|token| {
+            token
+                .parent()
+                .map_or(true, |parent| parent.kind() != latex::BLOCK_COMMENT)
+        })
+        .filter(|token| {
+            !matches!(
+                token.kind(),
+                latex::WHITESPACE | latex::LINE_BREAK | latex::COMMENT
+            )
+        })
+        .collect();
+
+    let declared_conditionals: FxHashSet<_> = tokens
+        .windows(2)
+        .filter(|pair| {
+            pair[0].kind() == latex::GENERIC_COMMAND_NAME
+                && pair[0].text() == "\\newif"
+                && pair[1].kind() == latex::GENERIC_COMMAND_NAME
+        })
+        .map(|pair| pair[1].text())
+        .collect();
+
+    let mut stack = Vec::new();
+    let mut previous_name = None;
+    for token in &tokens {
+        match token.kind() {
+            latex::GENERIC_COMMAND_NAME => {
+                let name = token.text();
+                let is_conditional =
+                    TEX_CONDITIONALS.contains(&name) || declared_conditionals.contains(name);
+                let is_declaration = previous_name == Some("\\newif")
+                    || token
+                        .parent()
+                        .map_or(false, |parent| parent.kind() == latex::COMMAND_DEFINITION);
+
+                if is_conditional && !is_declaration {
+                    stack.push(token.text_range());
+                }
+
+                previous_name = Some(name);
+            }
+            latex::END_BLOCK_COMMENT_NAME => {
+                if let Some(start) = stack.pop() {
+                    ranges.push((start.cover(token.text_range()), FoldingRangeKind::Region));
+                }
+
+                previous_name = None;
+            }
+            _ => previous_name = None,
+        };
+    }
+}
+
+fn find_bibtex_comments(
+    root: &bibtex::SyntaxNode,
+    ranges: &mut Vec<(TextRange, FoldingRangeKind)>,
+) {
+    let mut regions = Vec::new();
+    let mut comment_start = None;
+    for token in root
+        .children_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+    {
+        match token.kind() {
+            bibtex::TYPE if token.text().eq_ignore_ascii_case("@comment") => {
+                comment_start = Some(token.text_range().start());
+            }
+            bibtex::JUNK => {
+                let start = token.text_range().start();
+                if let Some(comment_start) = comment_start.take() {
+                    if let Some(end) = find_closing_curly(token.text()) {
+                        ranges.push((
+                            TextRange::new(comment_start, start + end),
+                            FoldingRangeKind::Comment,
+                        ));
+                    }
+                }
+
+                let mut offset = start;
+                for line in token.text().split_inclusive('\n') {
+                    let line_range = TextRange::at(offset, TextSize::of(line.trim_end()));
+                    offset += TextSize::of(line);
+                    match find_region_marker(line.trim()) {
+                        Some(true) => regions.push(line_range),
+                        Some(false) => {
+                            if let Some(start) = regions.pop() {
+                                ranges.push((start.cover(line_range), FoldingRangeKind::Region));
+                            }
+                        }
+                        None => {}
+                    };
+                }
+            }
+            _ => comment_start = None,
+        };
+    }
+}
+
+fn find_closing_curly(text: &str) -> Option<TextSize> {
+    let trimmed = text.trim_start();
+    if !trimmed.starts_with('{') {
+        return None;
+    }
+
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return TextSize::try_from(i + 1).ok();
+                }
+            }
+            _ => {}
+        };
+    }
+
+    None
+}
+
+fn find_region_marker(comment: &str) -> Option<bool> {
+    let marker = comment
+        .strip_prefix('%')?
+        .trim_start_matches('%')
+        .trim_start();
+    let marker = marker.strip_prefix('#').unwrap_or(marker);
+    if marker.starts_with("endregion") {
+        Some(false)
+    } else if marker.starts_with("region") {
+        Some(true)
+    } else {
+        None
+    }
+}
@@ -1,8 +1,13 @@
+mod command;
+mod environment;
 mod label;
 
 use lsp_types::{DocumentHighlight, DocumentHighlightParams};
 
-use self::label::find_label_highlights;
+use self::{
+    command::find_command_highlights, environment::find_environment_highlights,
+    label::find_label_highlights,
+};
 
 use super::{cursor::CursorContext, FeatureRequest};
 
@@ -11,4 +16,6 @@ pub fn find_document_highlights(
 ) -> Option<Vec<DocumentHighlight>> {
     let context = CursorContext::new(request);
     find_label_highlights(&context)
+        .or_else(|| find_command_highlights(&context))
+        .or_else(|| find_environment_highlights(&context))
 }
@@ -0,0 +1,67 @@
+use lsp_types::{DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams};
+use rowan::ast::AstNode;
+
+use crate::{features::cursor::CursorContext, syntax::latex, LineIndexExt};
+
+pub fn find_command_highlights(
+    context: &CursorContext<DocumentHighlightParams>,
+) -> Option<Vec<DocumentHighlight>> {
+    let name = context
+        .cursor
+        .as_latex()
+        .filter(|token| token.kind().is_command_name())?;
+
+    let is_user_defined = context
+        .request
+        .workspace
+        .documents_by_uri
+        .values()
+        .filter_map(|document| document.data.as_latex())
+        .any(|data| {
+            latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(latex::CommandDefinition::cast)
+                .filter_map(|def| def.defined_command())
+                .any(|command| command.text() == name.text())
+        });
+
+    if !is_user_defined {
+        return None;
+    }
+
+    let main_document = context.request.main_document();
+    let data = main_document.data.as_latex()?;
+    let root = latex::SyntaxNode::new_root(data.green.clone());
+
+    let definitions: Vec<_> = root
+        .descendants()
+        .filter_map(latex::CommandDefinition::cast)
+        .filter_map(|def| def.defined_command())
+        .filter(|command| command.text() == name.text())
+        .map(|command| command.text_range())
+        .collect();
+
+    let mut highlights: Vec<_> = definitions
+        .iter()
+        .map(|range| DocumentHighlight {
+            range: main_document.line_index.line_col_lsp_range(*range),
+            kind: Some(DocumentHighlightKind::WRITE),
+        })
+        .collect();
+
+    for range in root
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| token.kind() == latex::GENERIC_COMMAND_NAME)
+        .filter(|token| token.text() == name.text())
+        .map(|token| token.text_range())
+        .filter(|range| !definitions.contains(range))
+    {
+        highlights.push(DocumentHighlight {
+            range: main_document.line_index.line_col_lsp_range(range),
+            kind: Some(DocumentHighlightKind::READ),
+        });
+    }
+
+    Some(highlights)
+}
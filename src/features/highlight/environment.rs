@@ -0,0 +1,65 @@
+use lsp_types::{DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams};
+use rowan::ast::AstNode;
+
+use crate::{features::cursor::CursorContext, syntax::latex, LineIndexExt};
+
+pub fn find_environment_highlights(
+    context: &CursorContext<DocumentHighlightParams>,
+) -> Option<Vec<DocumentHighlight>> {
+    let (name_text, _) = context
+        .find_environment_name()
+        .or_else(|| context.find_environment_definition())?;
+
+    let is_user_defined = context
+        .request
+        .workspace
+        .documents_by_uri
+        .values()
+        .filter_map(|document| document.data.as_latex())
+        .any(|data| {
+            latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(find_definition_name)
+                .any(|name| name.to_string() == name_text)
+        });
+
+    if !is_user_defined {
+        return None;
+    }
+
+    let main_document = context.request.main_document();
+    let data = main_document.data.as_latex()?;
+
+    let mut highlights = Vec::new();
+    for node in latex::SyntaxNode::new_root(data.green.clone()).descendants() {
+        let (name, kind) = match find_definition_name(node.clone()) {
+            Some(name) => (name, DocumentHighlightKind::WRITE),
+            None => match latex::Begin::cast(node.clone())
+                .and_then(|begin| begin.name())
+                .or_else(|| latex::End::cast(node)?.name())
+                .and_then(|name| name.key())
+            {
+                Some(name) => (name, DocumentHighlightKind::READ),
+                None => continue,
+            },
+        };
+
+        if name.to_string() == name_text {
+            highlights.push(DocumentHighlight {
+                range: main_document
+                    .line_index
+                    .line_col_lsp_range(latex::small_range(&name)),
+                kind: Some(kind),
+            });
+        }
+    }
+
+    Some(highlights)
+}
+
+fn find_definition_name(node: latex::SyntaxNode) -> Option<latex::Key> {
+    latex::EnvironmentDefinition::cast(node.clone())
+        .and_then(|def| def.name())
+        .or_else(|| latex::TheoremDefinition::cast(node)?.name())?
+        .key()
+}
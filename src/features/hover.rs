@@ -1,7 +1,9 @@
 mod citation;
+mod command;
 mod component;
 mod entry_type;
 mod field;
+mod glossary;
 mod label;
 mod string_ref;
 
@@ -14,7 +16,8 @@ use crate::{
 };
 
 use self::{
-    component::find_component_hover, entry_type::find_entry_type_hover, field::find_field_hover,
+    command::find_user_command_hover, component::find_component_hover,
+    entry_type::find_entry_type_hover, field::find_field_hover, glossary::find_glossary_hover,
     label::find_label_hover, string_ref::find_string_reference_hover,
 };
 
@@ -25,7 +28,9 @@ pub fn find_hover(request: FeatureRequest<HoverParams>) -> Option<Hover> {
     log::debug!("[Hover] Cursor: {:?}", context.cursor);
     let result = find_label_hover(&context)
         .or_else(|| find_citation_hover(&context))
+        .or_else(|| find_glossary_hover(&context))
         .or_else(|| find_component_hover(&context))
+        .or_else(|| find_user_command_hover(&context))
         .or_else(|| find_string_reference_hover(&context))
         .or_else(|| find_field_hover(&context))
         .or_else(|| find_entry_type_hover(&context))?;
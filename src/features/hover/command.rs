@@ -0,0 +1,56 @@
+use lsp_types::{HoverParams, MarkupKind};
+use rowan::ast::AstNode;
+
+use crate::{features::cursor::CursorContext, syntax::latex};
+
+use super::HoverResult;
+
+pub(super) fn find_user_command_hover(context: &CursorContext<HoverParams>) -> Option<HoverResult> {
+    let name = context
+        .cursor
+        .as_latex()
+        .filter(|token| token.kind().is_command_name())?;
+
+    let definition = context
+        .request
+        .workspace
+        .documents_by_uri
+        .values()
+        .filter_map(|document| document.data.as_latex())
+        .flat_map(|data| latex::SyntaxNode::new_root(data.green.clone()).descendants())
+        .filter_map(latex::CommandDefinition::cast)
+        .find(|def| {
+            def.defined_command()
+                .map_or(false, |command| command.text() == name.text())
+        })?;
+
+    let text = definition.syntax().text().to_string();
+    let value = match definition.implementation() {
+        Some(body) => {
+            let offset =
+                body.syntax().text_range().start() - definition.syntax().text_range().start();
+            let (signature, body) = text.split_at(offset.into());
+            format!("{}{}", signature.trim_end(), truncate_body(body))
+        }
+        None => text.trim_end().to_string(),
+    };
+
+    Some(HoverResult {
+        range: name.text_range(),
+        value: format!("```latex\n{}\n```", value),
+        value_kind: MarkupKind::Markdown,
+    })
+}
+
+const MAX_BODY_LENGTH: usize = 80;
+
+fn truncate_body(body: &str) -> String {
+    let body = body.trim_end();
+    let first_line = body.lines().next().unwrap_or_default();
+    if first_line.len() == body.len() && body.chars().count() <= MAX_BODY_LENGTH {
+        return body.to_string();
+    }
+
+    let prefix: String = first_line.chars().take(MAX_BODY_LENGTH).collect();
+    format!("{} ...", prefix.trim_end())
+}
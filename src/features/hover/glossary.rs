@@ -0,0 +1,16 @@
+use lsp_types::{HoverParams, MarkupKind};
+
+use crate::{features::cursor::CursorContext, find_glossary_definition};
+
+use super::HoverResult;
+
+pub(super) fn find_glossary_hover(context: &CursorContext<HoverParams>) -> Option<HoverResult> {
+    let (name_text, range) = context.find_glossary_name()?;
+    let definition = find_glossary_definition(&context.request.workspace, &name_text)?;
+
+    Some(HoverResult {
+        range,
+        value: definition.reference()?,
+        value_kind: MarkupKind::PlainText,
+    })
+}
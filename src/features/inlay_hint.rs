@@ -0,0 +1,45 @@
+mod citation;
+mod label;
+
+use lsp_types::{InlayHint, InlayHintLabel, InlayHintParams};
+use rowan::TextSize;
+
+use crate::LineIndexExt;
+
+use self::{citation::find_citation_hints, label::find_label_hints};
+
+use super::FeatureRequest;
+
+pub fn find_inlay_hints(request: FeatureRequest<InlayHintParams>) -> Vec<InlayHint> {
+    let mut hints = Vec::new();
+    find_label_hints(&request, &mut hints);
+    if request.workspace.environment.options.inlay_hints.citations {
+        find_citation_hints(&request, &mut hints);
+    }
+
+    let main_document = request.main_document();
+    let range = main_document
+        .line_index
+        .offset_lsp_range(request.params.range);
+
+    hints
+        .into_iter()
+        .filter(|hint| range.contains_inclusive(hint.offset))
+        .map(|hint| InlayHint {
+            position: main_document.line_index.line_col_lsp(hint.offset),
+            label: InlayHintLabel::String(hint.label),
+            kind: None,
+            text_edits: None,
+            tooltip: None,
+            padding_left: Some(true),
+            padding_right: None,
+            data: None,
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+struct InternalInlayHint {
+    offset: TextSize,
+    label: String,
+}
@@ -0,0 +1,50 @@
+use lsp_types::InlayHintParams;
+use rowan::ast::AstNode;
+
+use crate::{
+    citation,
+    features::FeatureRequest,
+    syntax::{bibtex, latex},
+};
+
+use super::InternalInlayHint;
+
+pub(super) fn find_citation_hints(
+    request: &FeatureRequest<InlayHintParams>,
+    hints: &mut Vec<InternalInlayHint>,
+) -> Option<()> {
+    let data = request.main_document().data.as_latex()?;
+    for citation in latex::SyntaxNode::new_root(data.green.clone())
+        .descendants()
+        .filter_map(latex::Citation::cast)
+    {
+        let entries: Vec<_> = citation
+            .key_list()
+            .into_iter()
+            .flat_map(|group| group.keys())
+            .filter_map(|key| render_entry(request, &key.to_string()))
+            .collect();
+
+        if !entries.is_empty() {
+            hints.push(InternalInlayHint {
+                offset: latex::small_range(&citation).end(),
+                label: entries.join("; "),
+            });
+        }
+    }
+
+    Some(())
+}
+
+fn render_entry(request: &FeatureRequest<InlayHintParams>, key: &str) -> Option<String> {
+    request
+        .workspace
+        .documents_by_uri
+        .values()
+        .find_map(|document| {
+            let data = document.data.as_bibtex()?;
+            let root = bibtex::Root::cast(bibtex::SyntaxNode::new_root(data.green.clone()))?;
+            let entry = root.find_entry(key)?;
+            citation::render_short(&entry)
+        })
+}
@@ -0,0 +1,51 @@
+use lsp_types::InlayHintParams;
+use rowan::ast::AstNode;
+
+use crate::{
+    features::FeatureRequest,
+    label::{render_label, LabelledObject, RenderedLabel},
+    syntax::latex,
+};
+
+use super::InternalInlayHint;
+
+pub(super) fn find_label_hints(
+    request: &FeatureRequest<InlayHintParams>,
+    hints: &mut Vec<InternalInlayHint>,
+) -> Option<()> {
+    let data = request.main_document().data.as_latex()?;
+    for reference in latex::SyntaxNode::new_root(data.green.clone())
+        .descendants()
+        .filter_map(latex::LabelReference::cast)
+    {
+        let labels: Vec<_> = reference
+            .name_list()
+            .into_iter()
+            .flat_map(|group| group.keys())
+            .filter_map(|name| render_label(&request.workspace, &name.to_string(), None))
+            .filter_map(|label| render_number(&label))
+            .collect();
+
+        if !labels.is_empty() {
+            hints.push(InternalInlayHint {
+                offset: latex::small_range(&reference).end(),
+                label: labels.join(", "),
+            });
+        }
+    }
+
+    Some(())
+}
+
+fn render_number(label: &RenderedLabel) -> Option<String> {
+    let number = label.number.as_deref()?;
+    let text = match &label.object {
+        LabelledObject::Section { prefix, .. } => format!("{} {}", prefix, number),
+        LabelledObject::Float { kind, .. } => format!("{} {}", kind.as_str(), number),
+        LabelledObject::Theorem { kind, .. } => format!("{} {}", kind, number),
+        LabelledObject::Equation => format!("({})", number),
+        LabelledObject::EnumItem => format!("Item {}", number),
+    };
+
+    Some(text)
+}
@@ -1,4 +1,6 @@
+mod graphics;
 mod include;
+mod url;
 
 use std::sync::Arc;
 
@@ -7,7 +9,13 @@ use rowan::TextRange;
 
 use crate::LineIndexExt;
 
-use self::include::find_include_links;
+use self::{
+    graphics::find_graphics_links,
+    include::find_include_links,
+    url::{find_field_links, find_url_links},
+};
+
+pub(super) use self::graphics::{find_graphics_targets, is_file};
 
 use super::FeatureRequest;
 
@@ -15,6 +23,9 @@ pub fn find_document_links(request: FeatureRequest<DocumentLinkParams>) -> Vec<D
     let document = request.main_document();
     let mut results = Vec::new();
     find_include_links(&request, &mut results);
+    find_graphics_links(&request, &mut results);
+    find_url_links(&request, &mut results);
+    find_field_links(&request, &mut results);
     results
         .into_iter()
         .map(|result| DocumentLink {
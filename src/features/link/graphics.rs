@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use lsp_types::{DocumentLinkParams, Url};
+use rowan::ast::AstNode;
+
+use crate::{features::FeatureRequest, syntax::latex, Document, Workspace};
+
+use super::LinkResult;
+
+const GRAPHICS_EXTENSIONS: [&str; 7] = ["pdf", "png", "jpg", "jpeg", "eps", "ps", "svg"];
+
+pub(super) fn find_graphics_links(
+    request: &FeatureRequest<DocumentLinkParams>,
+    results: &mut Vec<LinkResult>,
+) -> Option<()> {
+    let main_document = request.main_document();
+    let data = main_document.data.as_latex()?;
+
+    for include in latex::SyntaxNode::new_root(data.green.clone())
+        .descendants()
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                latex::GRAPHICS_INCLUDE | latex::SVG_INCLUDE | latex::INKSCAPE_INCLUDE
+            )
+        })
+        .filter_map(latex::Include::cast)
+    {
+        for path in include.path_list().into_iter().flat_map(|list| list.keys()) {
+            let target =
+                find_graphics_targets(&request.workspace, main_document, &path.to_string())
+                    .into_iter()
+                    .find(|target| is_file(target));
+
+            if let Some(target) = target {
+                results.push(LinkResult {
+                    range: latex::small_range(&path),
+                    target,
+                });
+            }
+        }
+    }
+
+    Some(())
+}
+
+pub fn find_graphics_targets(
+    workspace: &Workspace,
+    document: &Document,
+    path: &str,
+) -> Vec<Arc<Url>> {
+    let base_uri = workspace
+        .environment
+        .options
+        .root_directory
+        .as_ref()
+        .and_then(|root_dir| {
+            let root_dir = workspace.environment.current_directory.join(root_dir);
+            Url::from_directory_path(root_dir).ok().map(Arc::new)
+        })
+        .unwrap_or_else(|| Arc::clone(&document.uri));
+
+    let mut dirs = vec![String::new()];
+    for data in workspace
+        .documents_by_uri
+        .values()
+        .filter_map(|document| document.data.as_latex())
+    {
+        dirs.extend(data.extras.graphics_paths.iter().cloned());
+    }
+
+    let mut targets = Vec::new();
+    for dir in dirs {
+        let stem = format!("{}{}", dir, path);
+        targets.extend(base_uri.join(&stem).ok().map(Arc::new));
+        for extension in GRAPHICS_EXTENSIONS {
+            let file = format!("{}.{}", stem, extension);
+            targets.extend(base_uri.join(&file).ok().map(Arc::new));
+        }
+    }
+
+    targets
+}
+
+pub fn is_file(uri: &Url) -> bool {
+    uri.to_file_path().map_or(false, |path| path.is_file())
+}
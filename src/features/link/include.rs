@@ -2,9 +2,9 @@ use std::sync::Arc;
 
 use lsp_types::DocumentLinkParams;
 
-use crate::features::FeatureRequest;
+use crate::{features::FeatureRequest, syntax::latex::ExplicitLinkKind};
 
-use super::LinkResult;
+use super::{is_file, LinkResult};
 
 pub(super) fn find_include_links(
     request: &FeatureRequest<DocumentLinkParams>,
@@ -13,19 +13,28 @@ pub(super) fn find_include_links(
     let data = request.main_document().data.as_latex()?;
 
     for include in &data.extras.explicit_links {
-        for target in &include.targets {
-            if request
-                .workspace
-                .documents_by_uri
-                .values()
-                .any(|document| document.uri.as_ref() == target.as_ref())
-            {
-                results.push(LinkResult {
-                    range: include.stem_range,
-                    target: Arc::clone(target),
-                });
-                break;
-            }
+        let target = include
+            .targets
+            .iter()
+            .find(|target| {
+                request
+                    .workspace
+                    .documents_by_uri
+                    .values()
+                    .any(|document| document.uri.as_ref() == target.as_ref())
+            })
+            .or_else(|| match include.kind {
+                ExplicitLinkKind::Package | ExplicitLinkKind::Class => {
+                    include.targets.iter().find(|target| is_file(target))
+                }
+                ExplicitLinkKind::Latex | ExplicitLinkKind::Bibtex => None,
+            });
+
+        if let Some(target) = target {
+            results.push(LinkResult {
+                range: include.stem_range,
+                target: Arc::clone(target),
+            });
         }
     }
 
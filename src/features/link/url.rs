@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use lsp_types::{DocumentLinkParams, Url};
+use rowan::{ast::AstNode, TextRange, TextSize};
+
+use crate::{
+    citation::field::text::TextFieldData,
+    features::FeatureRequest,
+    syntax::{
+        bibtex::{self, HasName, HasValue},
+        latex::{self, HasCurly},
+    },
+};
+
+use super::LinkResult;
+
+pub(super) fn find_url_links(
+    request: &FeatureRequest<DocumentLinkParams>,
+    results: &mut Vec<LinkResult>,
+) -> Option<()> {
+    let main_document = request.main_document();
+    let data = main_document.data.as_latex()?;
+
+    for command in latex::SyntaxNode::new_root(data.green.clone())
+        .descendants()
+        .filter_map(latex::GenericCommand::cast)
+        .filter(|command| {
+            command
+                .name()
+                .map_or(false, |name| matches!(name.text(), "\\url" | "\\href"))
+        })
+    {
+        let group = match command
+            .syntax()
+            .children()
+            .find_map(latex::CurlyGroup::cast)
+        {
+            Some(group) => group,
+            None => continue,
+        };
+
+        let (left, right) = match (group.left_curly(), group.right_curly()) {
+            (Some(left), Some(right)) => (left, right),
+            _ => continue,
+        };
+
+        let range = TextRange::new(left.text_range().end(), right.text_range().start());
+        let text = main_document.text[std::ops::Range::<usize>::from(range)].trim();
+        if let Ok(target) = Url::parse(text) {
+            results.push(LinkResult {
+                range: trim_range(&main_document.text, range),
+                target: Arc::new(target),
+            });
+        }
+    }
+
+    Some(())
+}
+
+pub(super) fn find_field_links(
+    request: &FeatureRequest<DocumentLinkParams>,
+    results: &mut Vec<LinkResult>,
+) -> Option<()> {
+    let main_document = request.main_document();
+    let data = main_document.data.as_bibtex()?;
+
+    for entry in bibtex::SyntaxNode::new_root(data.green.clone())
+        .children()
+        .filter_map(bibtex::Entry::cast)
+    {
+        let eprint_type = entry
+            .fields()
+            .find(|field| {
+                field.name_token().map_or(false, |name| {
+                    matches!(
+                        name.text().to_lowercase().as_str(),
+                        "eprinttype" | "archiveprefix"
+                    )
+                })
+            })
+            .and_then(|field| TextFieldData::parse(&field.value()?))
+            .map(|data| data.text);
+
+        for field in entry.fields() {
+            let (name, value) = match (field.name_token(), field.value()) {
+                (Some(name), Some(value)) => (name.text().to_lowercase(), value),
+                _ => continue,
+            };
+
+            // The rendered field text would turn `~` into a space,
+            // so the link target is read from the source instead.
+            let range = match value.syntax().kind() {
+                bibtex::CURLY_GROUP | bibtex::QUOTE_GROUP
+                    if value.syntax().text_range().len() >= TextSize::from(2) =>
+                {
+                    let range = value.syntax().text_range();
+                    TextRange::new(
+                        range.start() + TextSize::from(1),
+                        range.end() - TextSize::from(1),
+                    )
+                }
+                bibtex::LITERAL => value.syntax().text_range(),
+                _ => continue,
+            };
+
+            let text = main_document.text[std::ops::Range::<usize>::from(range)].trim();
+            let target = match name.as_str() {
+                "url" => Url::parse(text).ok(),
+                "doi" => Url::parse(text)
+                    .ok()
+                    .filter(|url| !url.cannot_be_a_base())
+                    .or_else(|| Url::parse(&format!("https://doi.org/{}", text)).ok()),
+                "eprint"
+                    if eprint_type
+                        .as_ref()
+                        .map_or(true, |ty| ty.eq_ignore_ascii_case("arxiv")) =>
+                {
+                    Url::parse(&format!("https://arxiv.org/abs/{}", text)).ok()
+                }
+                _ => None,
+            };
+
+            if let Some(target) = target {
+                results.push(LinkResult {
+                    range: trim_range(&main_document.text, range),
+                    target: Arc::new(target),
+                });
+            }
+        }
+    }
+
+    Some(())
+}
+
+fn trim_range(text: &str, range: TextRange) -> TextRange {
+    let slice = &text[std::ops::Range::<usize>::from(range)];
+    let start = range.start() + TextSize::of(&slice[..slice.len() - slice.trim_start().len()]);
+    let end = range.end() - TextSize::of(&slice[slice.trim_end().len()..]);
+    TextRange::new(start, end.max(start))
+}
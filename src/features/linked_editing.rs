@@ -0,0 +1,41 @@
+use lsp_types::{LinkedEditingRangeParams, LinkedEditingRanges};
+use rowan::{ast::AstNode, TextRange};
+
+use crate::{
+    syntax::latex::{self, HasCurly},
+    LineIndexExt,
+};
+
+use super::{cursor::CursorContext, FeatureRequest};
+
+const ENVIRONMENT_NAME_PATTERN: &str = r"[a-zA-Z0-9@:._\-]+\*?";
+
+pub fn find_linked_editing_ranges(
+    request: FeatureRequest<LinkedEditingRangeParams>,
+) -> Option<LinkedEditingRanges> {
+    let context = CursorContext::new(request);
+    let (_, _, group) = context.find_curly_group_word()?;
+    if !matches!(group.syntax().parent()?.kind(), latex::BEGIN | latex::END) {
+        return None;
+    }
+
+    let environment = latex::Environment::cast(group.syntax().parent()?.parent()?)?;
+    let begin = find_name_range(&environment.begin()?.name()?)?;
+    let end = find_name_range(&environment.end()?.name()?)?;
+
+    let line_index = &context.request.main_document().line_index;
+    Some(LinkedEditingRanges {
+        ranges: vec![
+            line_index.line_col_lsp_range(begin),
+            line_index.line_col_lsp_range(end),
+        ],
+        word_pattern: Some(ENVIRONMENT_NAME_PATTERN.into()),
+    })
+}
+
+fn find_name_range(group: &latex::CurlyGroupWord) -> Option<TextRange> {
+    group.key().map(|key| latex::small_range(&key)).or_else(|| {
+        let offset = group.left_curly()?.text_range().end();
+        Some(TextRange::empty(offset))
+    })
+}
@@ -25,6 +25,7 @@ pub enum Structure {
     Field,
     Argument,
     GlossaryEntry,
+    Key,
 }
 
 impl Structure {
@@ -57,6 +58,7 @@ impl Structure {
             Self::Field => CompletionItemKind::FIELD,
             Self::Argument => CompletionItemKind::VALUE,
             Self::GlossaryEntry => CompletionItemKind::KEYWORD,
+            Self::Key => CompletionItemKind::PROPERTY,
         }
     }
 
@@ -88,7 +90,8 @@ impl Structure {
             Self::Entry(BibtexEntryTypeCategory::Thesis) => SymbolKind::OBJECT,
             Self::Field => SymbolKind::FIELD,
             Self::Argument => SymbolKind::NUMBER,
-            Self::GlossaryEntry => unimplemented!(),
+            Self::GlossaryEntry => SymbolKind::KEY,
+            Self::Key => SymbolKind::KEY,
         }
     }
 }
@@ -0,0 +1,172 @@
+use std::{path::Path, sync::Arc};
+
+use lsp_types::{Range, TextDocumentIdentifier, Url};
+use rowan::{ast::AstNode, TextRange};
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    syntax::latex::{self, ExplicitLinkKind},
+    Document, LineIndexExt, Workspace,
+};
+
+use super::{
+    link::{find_graphics_targets, is_file},
+    FeatureRequest,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTreeParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProjectTreeNodeKind {
+    Latex,
+    Bibtex,
+    Graphics,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTreeNode {
+    pub uri: Url,
+    pub kind: ProjectTreeNodeKind,
+    pub range: Option<Range>,
+    pub exists: bool,
+    pub children: Vec<ProjectTreeNode>,
+}
+
+pub fn find_project_tree(request: FeatureRequest<ProjectTreeParams>) -> Option<ProjectTreeNode> {
+    let root = request
+        .workspace
+        .find_parent(&request.uri)
+        .unwrap_or_else(|| request.main_document().clone());
+
+    let mut visited = FxHashSet::default();
+    visited.insert(Arc::clone(&root.uri));
+    Some(ProjectTreeNode {
+        uri: root.uri.as_ref().clone(),
+        kind: ProjectTreeNodeKind::Latex,
+        range: None,
+        exists: true,
+        children: find_children(&request.workspace, &root, &mut visited),
+    })
+}
+
+fn find_children(
+    workspace: &Workspace,
+    document: &Document,
+    visited: &mut FxHashSet<Arc<Url>>,
+) -> Vec<ProjectTreeNode> {
+    let data = match document.data.as_latex() {
+        Some(data) => data,
+        None => return Vec::new(),
+    };
+
+    let root = latex::SyntaxNode::new_root(data.green.clone());
+    let mut children = Vec::new();
+    for link in &data.extras.explicit_links {
+        let kind = match link.kind {
+            ExplicitLinkKind::Latex => ProjectTreeNodeKind::Latex,
+            ExplicitLinkKind::Bibtex => ProjectTreeNodeKind::Bibtex,
+            ExplicitLinkKind::Package | ExplicitLinkKind::Class => continue,
+        };
+
+        let range = find_command_range(&root, link.stem_range).unwrap_or(link.stem_range);
+        let child = match link
+            .targets
+            .iter()
+            .find_map(|target| workspace.documents_by_uri.get(target))
+        {
+            Some(child) => {
+                let grandchildren = if visited.insert(Arc::clone(&child.uri)) {
+                    find_children(workspace, child, visited)
+                } else {
+                    Vec::new()
+                };
+
+                ProjectTreeNode {
+                    uri: child.uri.as_ref().clone(),
+                    kind,
+                    range: None,
+                    exists: true,
+                    children: grandchildren,
+                }
+            }
+            None => match resolve_file(&link.targets) {
+                Some(child) => child,
+                None => continue,
+            },
+        };
+
+        children.push((
+            range,
+            ProjectTreeNode {
+                kind,
+                range: Some(document.line_index.line_col_lsp_range(range)),
+                ..child
+            },
+        ));
+    }
+
+    for include in root
+        .descendants()
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                latex::GRAPHICS_INCLUDE | latex::SVG_INCLUDE | latex::INKSCAPE_INCLUDE
+            )
+        })
+        .filter_map(latex::Include::cast)
+    {
+        for path in include.path_list().into_iter().flat_map(|list| list.keys()) {
+            let targets = find_graphics_targets(workspace, document, &path.to_string());
+            if let Some(child) = resolve_file(&targets) {
+                let range = latex::small_range(&include);
+                children.push((
+                    range,
+                    ProjectTreeNode {
+                        kind: ProjectTreeNodeKind::Graphics,
+                        range: Some(document.line_index.line_col_lsp_range(range)),
+                        ..child
+                    },
+                ));
+            }
+        }
+    }
+
+    children.sort_by_key(|(range, _)| range.start());
+    children.into_iter().map(|(_, child)| child).collect()
+}
+
+fn find_command_range(root: &latex::SyntaxNode, stem_range: TextRange) -> Option<TextRange> {
+    root.covering_element(stem_range)
+        .ancestors()
+        .find_map(|node| {
+            latex::Include::cast(node.clone())
+                .map(|include| latex::small_range(&include))
+                .or_else(|| latex::Import::cast(node).map(|import| latex::small_range(&import)))
+        })
+}
+
+fn resolve_file(targets: &[Arc<Url>]) -> Option<ProjectTreeNode> {
+    let existing = targets.iter().find(|target| is_file(target));
+
+    let uri = existing
+        .or_else(|| {
+            targets
+                .iter()
+                .find(|target| Path::new(target.path()).extension().is_some())
+        })
+        .or_else(|| targets.first())?;
+    Some(ProjectTreeNode {
+        uri: uri.as_ref().clone(),
+        kind: ProjectTreeNodeKind::Latex,
+        range: None,
+        exists: existing.is_some(),
+        children: Vec::new(),
+    })
+}
@@ -1,4 +1,6 @@
+mod command;
 mod entry;
+mod environment;
 mod label;
 mod string;
 
@@ -6,11 +8,16 @@ use std::sync::Arc;
 
 use lsp_types::{Location, ReferenceParams, Url};
 use rowan::TextRange;
+use rustc_hash::FxHashMap;
 
-use crate::LineIndexExt;
+use crate::{LineIndexExt, Workspace};
 
 use self::{
-    entry::find_entry_references, label::find_label_references, string::find_string_references,
+    command::{command_usages, find_command_references},
+    entry::{citation_keys, find_entry_references},
+    environment::find_environment_references,
+    label::{find_label_references, label_references},
+    string::find_string_references,
 };
 
 use super::{cursor::CursorContext, FeatureRequest};
@@ -22,12 +29,79 @@ pub fn find_all_references(request: FeatureRequest<ReferenceParams>) -> Vec<Loca
     find_label_references(&context, &mut results);
     find_entry_references(&context, &mut results);
     find_string_references(&context, &mut results);
+    find_command_references(&context, &mut results);
+    find_environment_references(&context, &mut results);
+    into_locations(&context.request.workspace, results)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ReferenceKind {
+    Label,
+    Entry,
+    Command,
+}
+
+/// The references of every label, entry and command in the workspace.
+/// It uses the same collectors as `find_all_references`, so the results match
+/// a reference request without declarations.
+#[derive(Debug, Default)]
+pub struct ReferenceIndex {
+    references: FxHashMap<(ReferenceKind, String), Vec<ReferenceResult>>,
+}
+
+impl ReferenceIndex {
+    pub fn new(workspace: &Workspace) -> Self {
+        let mut index = Self::default();
+        for document in workspace.documents_by_uri.values() {
+            let data = match document.data.as_latex() {
+                Some(data) => data,
+                None => continue,
+            };
+
+            for name in label_references(data, false) {
+                index.push(ReferenceKind::Label, &name.text, &document.uri, name.range);
+            }
+
+            for (key, range) in citation_keys(data) {
+                index.push(ReferenceKind::Entry, &key, &document.uri, range);
+            }
+
+            for command in command_usages(data) {
+                let range = command.text_range();
+                index.push(ReferenceKind::Command, command.text(), &document.uri, range);
+            }
+        }
+
+        index
+    }
+
+    fn push(&mut self, kind: ReferenceKind, name: &str, uri: &Arc<Url>, range: TextRange) {
+        self.references
+            .entry((kind, name.to_string()))
+            .or_default()
+            .push(ReferenceResult {
+                uri: Arc::clone(uri),
+                range,
+            });
+    }
+
+    pub fn find(&self, workspace: &Workspace, kind: ReferenceKind, name: &str) -> Vec<Location> {
+        let results = self
+            .references
+            .get(&(kind, name.to_string()))
+            .cloned()
+            .unwrap_or_default();
+
+        into_locations(workspace, results)
+    }
+}
 
+fn into_locations(workspace: &Workspace, results: Vec<ReferenceResult>) -> Vec<Location> {
     results
         .into_iter()
         .map(|result| Location {
             uri: result.uri.as_ref().clone(),
-            range: context.request.workspace.documents_by_uri[&result.uri]
+            range: workspace.documents_by_uri[&result.uri]
                 .line_index
                 .line_col_lsp_range(result.range),
         })
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use lsp_types::ReferenceParams;
+use rowan::ast::AstNode;
+use rustc_hash::FxHashSet;
+
+use crate::{features::cursor::CursorContext, syntax::latex, LatexDocumentData};
+
+use super::ReferenceResult;
+
+pub(super) fn find_command_references(
+    context: &CursorContext<ReferenceParams>,
+    results: &mut Vec<ReferenceResult>,
+) -> Option<()> {
+    let name = context
+        .cursor
+        .as_latex()
+        .filter(|token| token.kind().is_command_name())?;
+
+    let mut definitions = Vec::new();
+    for document in context.request.workspace.documents_by_uri.values() {
+        if let Some(data) = document.data.as_latex() {
+            command_definitions(data)
+                .filter(|command| command.text() == name.text())
+                .for_each(|command| {
+                    let uri = Arc::clone(&document.uri);
+                    definitions.push(ReferenceResult {
+                        uri,
+                        range: command.text_range(),
+                    });
+                });
+        }
+    }
+
+    if definitions.is_empty() {
+        return None;
+    }
+
+    for document in context.request.workspace.documents_by_uri.values() {
+        if let Some(data) = document.data.as_latex() {
+            command_usages(data)
+                .filter(|token| token.text() == name.text())
+                .for_each(|token| {
+                    let uri = Arc::clone(&document.uri);
+                    results.push(ReferenceResult {
+                        uri,
+                        range: token.text_range(),
+                    });
+                });
+        }
+    }
+
+    if context.request.params.context.include_declaration {
+        results.append(&mut definitions);
+    }
+
+    Some(())
+}
+
+fn command_definitions(data: &LatexDocumentData) -> impl Iterator<Item = latex::SyntaxToken> {
+    latex::SyntaxNode::new_root(data.green.clone())
+        .descendants()
+        .filter_map(latex::CommandDefinition::cast)
+        .filter_map(|def| def.defined_command())
+}
+
+pub(super) fn command_usages(data: &LatexDocumentData) -> impl Iterator<Item = latex::SyntaxToken> {
+    let definitions: FxHashSet<_> = command_definitions(data)
+        .map(|command| command.text_range())
+        .collect();
+
+    latex::SyntaxNode::new_root(data.green.clone())
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| token.kind() == latex::GENERIC_COMMAND_NAME)
+        .filter(move |token| !definitions.contains(&token.text_range()))
+}
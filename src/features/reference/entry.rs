@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use lsp_types::ReferenceParams;
-use rowan::ast::AstNode;
+use rowan::{ast::AstNode, TextRange};
 
 use crate::{
     features::cursor::CursorContext,
@@ -9,7 +9,7 @@ use crate::{
         bibtex::{self, HasName},
         latex,
     },
-    DocumentData,
+    DocumentData, LatexDocumentData,
 };
 
 use super::ReferenceResult;
@@ -26,14 +26,9 @@ pub(super) fn find_entry_references(
     for document in context.request.workspace.documents_by_uri.values() {
         match &document.data {
             DocumentData::Latex(data) => {
-                latex::SyntaxNode::new_root(data.green.clone())
-                    .descendants()
-                    .filter_map(latex::Citation::cast)
-                    .filter_map(|citation| citation.key_list())
-                    .flat_map(|keys| keys.keys())
-                    .filter(|key| key.to_string() == key_text)
-                    .map(|key| latex::small_range(&key))
-                    .for_each(|range| {
+                citation_keys(data)
+                    .filter(|(key, _)| *key == key_text)
+                    .for_each(|(_, range)| {
                         let uri = Arc::clone(&document.uri);
                         results.push(ReferenceResult { uri, range });
                     });
@@ -56,3 +51,12 @@ pub(super) fn find_entry_references(
 
     Some(())
 }
+
+pub(super) fn citation_keys(data: &LatexDocumentData) -> impl Iterator<Item = (String, TextRange)> {
+    latex::SyntaxNode::new_root(data.green.clone())
+        .descendants()
+        .filter_map(latex::Citation::cast)
+        .filter_map(|citation| citation.key_list())
+        .flat_map(|keys| keys.keys())
+        .map(|key| (key.to_string(), latex::small_range(&key)))
+}
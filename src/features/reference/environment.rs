@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use lsp_types::ReferenceParams;
+use rowan::ast::AstNode;
+
+use crate::{features::cursor::CursorContext, syntax::latex};
+
+use super::ReferenceResult;
+
+pub(super) fn find_environment_references(
+    context: &CursorContext<ReferenceParams>,
+    results: &mut Vec<ReferenceResult>,
+) -> Option<()> {
+    let (name_text, _) = context
+        .find_environment_name()
+        .or_else(|| context.find_environment_definition())?;
+
+    let mut definitions = Vec::new();
+    for document in context.request.workspace.documents_by_uri.values() {
+        if let Some(data) = document.data.as_latex() {
+            latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(|node| {
+                    latex::EnvironmentDefinition::cast(node.clone())
+                        .and_then(|def| def.name())
+                        .or_else(|| latex::TheoremDefinition::cast(node)?.name())
+                })
+                .filter_map(|name| name.key())
+                .filter(|name| name.to_string() == name_text)
+                .for_each(|name| {
+                    let uri = Arc::clone(&document.uri);
+                    definitions.push(ReferenceResult {
+                        uri,
+                        range: latex::small_range(&name),
+                    });
+                });
+        }
+    }
+
+    if definitions.is_empty() {
+        return None;
+    }
+
+    for document in context.request.workspace.documents_by_uri.values() {
+        if let Some(data) = document.data.as_latex() {
+            latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(|node| {
+                    latex::Begin::cast(node.clone())
+                        .and_then(|begin| begin.name())
+                        .or_else(|| latex::End::cast(node)?.name())
+                })
+                .filter_map(|name| name.key())
+                .filter(|name| name.to_string() == name_text)
+                .for_each(|name| {
+                    let uri = Arc::clone(&document.uri);
+                    let range = latex::small_range(&name);
+                    results.push(ReferenceResult { uri, range });
+                });
+        }
+    }
+
+    if context.request.params.context.include_declaration {
+        results.append(&mut definitions);
+    }
+
+    Some(())
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use lsp_types::ReferenceParams;
 
-use crate::features::cursor::CursorContext;
+use crate::{features::cursor::CursorContext, syntax::latex, LatexDocumentData};
 
 use super::ReferenceResult;
 
@@ -14,16 +14,11 @@ pub(super) fn find_label_references(
         .find_label_name_key()
         .or_else(|| context.find_label_name_command())?;
 
+    let include_declaration = context.request.params.context.include_declaration;
     for document in context.request.workspace.documents_by_uri.values() {
         if let Some(data) = document.data.as_latex() {
-            for name in data
-                .extras
-                .label_names
-                .iter()
-                .filter(|name| name.text == name_text)
-                .filter(|name| {
-                    !name.is_definition || context.request.params.context.include_declaration
-                })
+            for name in
+                label_references(data, include_declaration).filter(|name| name.text == name_text)
             {
                 results.push(ReferenceResult {
                     uri: Arc::clone(&document.uri),
@@ -35,3 +30,13 @@ pub(super) fn find_label_references(
 
     Some(())
 }
+
+pub(super) fn label_references(
+    data: &LatexDocumentData,
+    include_declaration: bool,
+) -> impl Iterator<Item = &latex::LabelName> {
+    data.extras
+        .label_names
+        .iter()
+        .filter(move |name| !name.is_definition || include_declaration)
+}
@@ -1,5 +1,7 @@
 mod command;
 mod entry;
+mod environment;
+mod glossary;
 mod label;
 
 use std::sync::Arc;
@@ -13,6 +15,8 @@ use crate::LineIndexExt;
 use self::{
     command::{prepare_command_rename, rename_command},
     entry::{prepare_entry_rename, rename_entry},
+    environment::{prepare_environment_rename, rename_environment},
+    glossary::{prepare_glossary_rename, rename_glossary_entry},
     label::{prepare_label_rename, rename_label},
 };
 
@@ -22,6 +26,8 @@ pub fn prepare_rename_all(request: FeatureRequest<TextDocumentPositionParams>) -
     let context = CursorContext::new(request);
     let range = prepare_entry_rename(&context)
         .or_else(|| prepare_label_rename(&context))
+        .or_else(|| prepare_environment_rename(&context))
+        .or_else(|| prepare_glossary_rename(&context))
         .or_else(|| prepare_command_rename(&context))?;
 
     let line_index = &context.request.main_document().line_index;
@@ -32,6 +38,8 @@ pub fn rename_all(request: FeatureRequest<RenameParams>) -> Option<WorkspaceEdit
     let context = CursorContext::new(request);
     let result = rename_entry(&context)
         .or_else(|| rename_label(&context))
+        .or_else(|| rename_environment(&context))
+        .or_else(|| rename_glossary_entry(&context))
         .or_else(|| rename_command(&context))?;
 
     let changes = result
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use lsp_types::RenameParams;
+use rowan::{ast::AstNode, TextRange};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    features::cursor::{CursorContext, HasPosition},
+    syntax::latex,
+};
+
+use super::{Indel, RenameResult};
+
+pub(super) fn prepare_environment_rename<P: HasPosition>(
+    context: &CursorContext<P>,
+) -> Option<TextRange> {
+    let (name_text, range) = context
+        .find_environment_name()
+        .or_else(|| context.find_environment_definition())?;
+
+    context
+        .request
+        .workspace
+        .documents_by_uri
+        .values()
+        .filter_map(|document| document.data.as_latex())
+        .any(|data| {
+            latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(find_definition_name)
+                .any(|name| name.to_string() == name_text)
+        })
+        .then(|| range)
+}
+
+pub(super) fn rename_environment(context: &CursorContext<RenameParams>) -> Option<RenameResult> {
+    prepare_environment_rename(context)?;
+    let (name_text, _) = context
+        .find_environment_name()
+        .or_else(|| context.find_environment_definition())?;
+
+    let mut changes = FxHashMap::default();
+    for document in context.request.workspace.documents_by_uri.values() {
+        if let Some(data) = document.data.as_latex() {
+            let edits = latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(|node| {
+                    find_definition_name(node.clone()).or_else(|| {
+                        latex::Begin::cast(node.clone())
+                            .and_then(|begin| begin.name())
+                            .or_else(|| latex::End::cast(node)?.name())?
+                            .key()
+                    })
+                })
+                .filter(|name| name.to_string() == name_text)
+                .map(|name| Indel {
+                    delete: latex::small_range(&name),
+                    insert: context.request.params.new_name.clone(),
+                })
+                .collect();
+
+            changes.insert(Arc::clone(&document.uri), edits);
+        }
+    }
+
+    Some(RenameResult { changes })
+}
+
+fn find_definition_name(node: latex::SyntaxNode) -> Option<latex::Key> {
+    latex::EnvironmentDefinition::cast(node.clone())
+        .and_then(|def| def.name())
+        .or_else(|| latex::TheoremDefinition::cast(node)?.name())?
+        .key()
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use lsp_types::RenameParams;
+use rowan::{ast::AstNode, TextRange};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    features::cursor::{CursorContext, HasPosition},
+    syntax::latex,
+};
+
+use super::{Indel, RenameResult};
+
+pub(super) fn prepare_glossary_rename<P: HasPosition>(
+    context: &CursorContext<P>,
+) -> Option<TextRange> {
+    let (_, range) = context.find_glossary_name()?;
+    Some(range)
+}
+
+pub(super) fn rename_glossary_entry(context: &CursorContext<RenameParams>) -> Option<RenameResult> {
+    let (name_text, _) = context.find_glossary_name()?;
+
+    let mut changes = FxHashMap::default();
+    for document in context.request.workspace.documents_by_uri.values() {
+        if let Some(data) = document.data.as_latex() {
+            let edits = latex::SyntaxNode::new_root(data.green.clone())
+                .descendants()
+                .filter_map(|node| {
+                    latex::GlossaryEntryDefinition::cast(node.clone())
+                        .and_then(|entry| entry.name())
+                        .or_else(|| latex::GlossaryEntryReference::cast(node.clone())?.name())
+                        .or_else(|| latex::AcronymDefinition::cast(node.clone())?.name())
+                        .or_else(|| latex::AcronymDeclaration::cast(node.clone())?.name())
+                        .or_else(|| latex::AcronymReference::cast(node)?.name())?
+                        .key()
+                })
+                .filter(|name| name.to_string() == name_text)
+                .map(|name| Indel {
+                    delete: latex::small_range(&name),
+                    insert: context.request.params.new_name.clone(),
+                })
+                .collect();
+
+            changes.insert(Arc::clone(&document.uri), edits);
+        }
+    }
+
+    Some(RenameResult { changes })
+}
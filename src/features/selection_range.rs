@@ -0,0 +1,163 @@
+use lsp_types::{SelectionRange, SelectionRangeParams};
+use rowan::{ast::AstNode, NodeOrToken, TextRange, TextSize};
+
+use crate::{
+    syntax::{bibtex, latex},
+    DocumentData, LineIndexExt,
+};
+
+use super::FeatureRequest;
+
+pub fn find_selection_ranges(request: FeatureRequest<SelectionRangeParams>) -> Vec<SelectionRange> {
+    let main_document = request.main_document();
+    request
+        .params
+        .positions
+        .iter()
+        .map(|&position| {
+            let offset = main_document.line_index.offset_lsp(position);
+            let ranges = match &main_document.data {
+                DocumentData::Latex(data) => {
+                    let root = latex::SyntaxNode::new_root(data.green.clone());
+                    find_latex_ranges(&root, offset)
+                }
+                DocumentData::Bibtex(data) => {
+                    let root = bibtex::SyntaxNode::new_root(data.green.clone());
+                    find_bibtex_ranges(&root, offset)
+                }
+                DocumentData::BuildLog(_) => Vec::new(),
+            };
+
+            ranges
+                .into_iter()
+                .rev()
+                .fold(None, |parent, range| {
+                    Some(SelectionRange {
+                        range: main_document.line_index.line_col_lsp_range(range),
+                        parent: parent.map(Box::new),
+                    })
+                })
+                .unwrap_or_else(|| SelectionRange {
+                    range: lsp_types::Range::new(position, position),
+                    parent: None,
+                })
+        })
+        .collect()
+}
+
+fn find_latex_ranges(root: &latex::SyntaxNode, offset: TextSize) -> Vec<TextRange> {
+    let mut ranges = Vec::new();
+    let tokens = root.token_at_offset(offset);
+    let token = match tokens
+        .clone()
+        .right_biased()
+        .filter(|token| is_latex_word(token.kind()))
+        .or_else(|| {
+            tokens
+                .clone()
+                .left_biased()
+                .filter(|token| is_latex_word(token.kind()))
+        })
+        .or_else(|| tokens.right_biased())
+    {
+        Some(token) => token,
+        None => return ranges,
+    };
+
+    if is_latex_word(token.kind()) {
+        push_range(&mut ranges, token.text_range());
+    }
+
+    for node in token.parent_ancestors() {
+        if let Some(environment) = latex::Environment::cast(node.clone()) {
+            if let Some(body) = find_environment_body(&environment) {
+                if ranges
+                    .last()
+                    .map_or(false, |range| body.contains_range(*range))
+                {
+                    push_range(&mut ranges, body);
+                }
+            }
+        }
+
+        push_range(&mut ranges, find_latex_node_range(&node));
+    }
+
+    ranges
+}
+
+fn find_latex_node_range(node: &latex::SyntaxNode) -> TextRange {
+    let mut last = node.last_token();
+    while let Some(current) = last.clone().filter(|token| is_latex_trivia(token.kind())) {
+        last = current.prev_token();
+    }
+
+    let start = node.text_range().start();
+    let end = last.map_or(start, |token| token.text_range().end().max(start));
+    TextRange::new(start, end)
+}
+
+fn find_environment_body(environment: &latex::Environment) -> Option<TextRange> {
+    let start = environment.begin()?.syntax().text_range().end();
+    let end = environment.end()?.syntax().text_range().start();
+    let last = environment
+        .syntax()
+        .children_with_tokens()
+        .filter(|child| {
+            let range = child.text_range();
+            range.start() >= start && range.end() <= end
+        })
+        .filter_map(|child| match child {
+            NodeOrToken::Node(node) => Some(find_latex_node_range(&node)),
+            NodeOrToken::Token(token) if is_latex_trivia(token.kind()) => None,
+            NodeOrToken::Token(token) => Some(token.text_range()),
+        })
+        .last()?;
+
+    Some(TextRange::new(start, last.end()))
+}
+
+fn find_bibtex_ranges(root: &bibtex::SyntaxNode, offset: TextSize) -> Vec<TextRange> {
+    let mut ranges = Vec::new();
+    let token = match root.token_at_offset(offset).right_biased() {
+        Some(token) => token,
+        None => return ranges,
+    };
+
+    if token.kind() != bibtex::WHITESPACE {
+        push_range(&mut ranges, token.text_range());
+    }
+
+    for node in token.parent_ancestors() {
+        let mut last = node.last_token();
+        while let Some(current) = last
+            .clone()
+            .filter(|token| token.kind() == bibtex::WHITESPACE)
+        {
+            last = current.prev_token();
+        }
+
+        let start = node.text_range().start();
+        let end = last.map_or(start, |token| token.text_range().end().max(start));
+        push_range(&mut ranges, TextRange::new(start, end));
+    }
+
+    ranges
+}
+
+fn push_range(ranges: &mut Vec<TextRange>, range: TextRange) {
+    if ranges
+        .last()
+        .map_or(true, |last| *last != range && range.contains_range(*last))
+    {
+        ranges.push(range);
+    }
+}
+
+fn is_latex_trivia(kind: latex::SyntaxKind) -> bool {
+    matches!(kind, latex::LINE_BREAK | latex::WHITESPACE | latex::COMMENT)
+}
+
+fn is_latex_word(kind: latex::SyntaxKind) -> bool {
+    kind == latex::WORD || kind.is_command_name()
+}
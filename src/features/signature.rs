@@ -0,0 +1,360 @@
+use lsp_types::{
+    ParameterInformation, ParameterLabel, SignatureHelp, SignatureHelpParams, SignatureInformation,
+};
+use rowan::{ast::AstNode, TextSize};
+
+use crate::{
+    component_db::{ComponentCommand, COMPONENT_DATABASE},
+    syntax::latex,
+    EnvironmentParameter, Workspace, LANGUAGE_DATA,
+};
+
+use super::{cursor::CursorContext, FeatureRequest};
+
+pub fn find_signature_help(request: FeatureRequest<SignatureHelpParams>) -> Option<SignatureHelp> {
+    let context = CursorContext::new(request);
+    let data = context.request.main_document().data.as_latex()?;
+    let root = latex::SyntaxNode::new_root(data.green.clone());
+    let token = root.token_at_offset(context.offset).left_biased()?;
+
+    token
+        .parent()?
+        .ancestors()
+        .filter(|node| is_group(node.kind()) && is_inside_group(node, context.offset))
+        .find_map(|group| {
+            find_environment_signature(&context.request.workspace, &group)
+                .or_else(|| find_command_signature(&context.request.workspace, &group))
+        })
+}
+
+#[derive(Debug)]
+struct Signature {
+    label: String,
+    parameters: Vec<Parameter>,
+}
+
+#[derive(Debug)]
+struct Parameter {
+    label: String,
+    is_optional: bool,
+}
+
+impl Signature {
+    fn new(name: String, parameters: Vec<Parameter>) -> Option<Self> {
+        if parameters.is_empty() {
+            None
+        } else {
+            Some(Self {
+                label: name,
+                parameters,
+            })
+        }
+    }
+
+    fn from_user_parameters(name: String, parameters: &[latex::UserParameter]) -> Option<Self> {
+        let parameters = parameters
+            .iter()
+            .map(|param| match (param.kind, &param.default) {
+                (latex::UserParameterKind::Optional, Some(default)) if !default.is_empty() => {
+                    Parameter {
+                        label: format!("[{}]", default),
+                        is_optional: true,
+                    }
+                }
+                (latex::UserParameterKind::Optional, _) => Parameter {
+                    label: format!("[#{}]", param.number),
+                    is_optional: true,
+                },
+                (latex::UserParameterKind::Mandatory, _) => Parameter {
+                    label: format!("{{#{}}}", param.number),
+                    is_optional: false,
+                },
+            })
+            .collect();
+
+        Self::new(name, parameters)
+    }
+
+    fn from_component_command(name: String, command: &ComponentCommand) -> Option<Self> {
+        // The component database only records the mandatory arguments of a command.
+        let parameters = command
+            .parameters
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                let label = if param.0.is_empty() {
+                    format!("{{#{}}}", i + 1)
+                } else {
+                    let names: Vec<_> = param.0.iter().map(|arg| arg.name.as_str()).collect();
+                    format!("{{{}}}", names.join("|"))
+                };
+
+                Parameter {
+                    label,
+                    is_optional: false,
+                }
+            })
+            .collect();
+
+        Self::new(name, parameters)
+    }
+
+    fn from_environment_parameters(
+        name: String,
+        parameters: &[EnvironmentParameter],
+    ) -> Option<Self> {
+        let parameters = parameters
+            .iter()
+            .map(|param| Parameter {
+                label: if param.optional {
+                    format!("[{}]", param.name)
+                } else {
+                    format!("{{{}}}", param.name)
+                },
+                is_optional: param.optional,
+            })
+            .collect();
+
+        Self::new(name, parameters)
+    }
+
+    fn into_help(
+        self,
+        group: &latex::SyntaxNode,
+        arguments: &[latex::SyntaxNode],
+    ) -> SignatureHelp {
+        let is_optional = is_brack_group(group);
+        let index = arguments
+            .iter()
+            .take_while(|argument| *argument != group)
+            .filter(|argument| is_brack_group(argument) == is_optional)
+            .count();
+
+        let active_parameter = self
+            .parameters
+            .iter()
+            .enumerate()
+            .filter(|(_, param)| param.is_optional == is_optional)
+            .nth(index)
+            .map(|(i, _)| i as u32);
+
+        let mut label = self.label;
+        let mut parameters = Vec::new();
+        for param in self.parameters {
+            let start = label.encode_utf16().count() as u32;
+            label.push_str(&param.label);
+            let end = label.encode_utf16().count() as u32;
+            parameters.push(ParameterInformation {
+                label: ParameterLabel::LabelOffsets([start, end]),
+                documentation: None,
+            });
+        }
+
+        SignatureHelp {
+            signatures: vec![SignatureInformation {
+                label,
+                documentation: None,
+                parameters: Some(parameters),
+                active_parameter,
+            }],
+            active_signature: Some(0),
+            active_parameter,
+        }
+    }
+}
+
+fn find_command_signature(
+    workspace: &Workspace,
+    group: &latex::SyntaxNode,
+) -> Option<SignatureHelp> {
+    let command = group.parent()?;
+    let name = command
+        .first_token()
+        .filter(|token| token.kind().is_command_name())?;
+
+    let arguments: Vec<_> = command
+        .children()
+        .take_while(|node| is_group(node.kind()))
+        .collect();
+
+    if !arguments.contains(group) {
+        return None;
+    }
+
+    let signature = find_user_command_signature(workspace, name.text())
+        .or_else(|| {
+            name.text()
+                .strip_suffix('*')
+                .and_then(|name| find_user_command_signature(workspace, name))
+        })
+        .or_else(|| find_component_command_signature(workspace, name.text()))?;
+
+    Some(signature.into_help(group, &arguments))
+}
+
+fn find_user_command_signature(workspace: &Workspace, name: &str) -> Option<Signature> {
+    workspace
+        .documents_by_uri
+        .values()
+        .filter_map(|document| document.data.as_latex())
+        .flat_map(|data| data.extras.user_commands.iter())
+        .filter(|command| command.name == name)
+        .find_map(|command| Signature::from_user_parameters(name.into(), &command.parameters))
+}
+
+fn find_component_command_signature(workspace: &Workspace, name: &str) -> Option<Signature> {
+    COMPONENT_DATABASE
+        .linked_components(workspace)
+        .into_iter()
+        .flat_map(|component| component.commands.iter())
+        .filter(|command| command.name == name[1..])
+        .find_map(|command| Signature::from_component_command(name.into(), command))
+}
+
+fn find_environment_signature(
+    workspace: &Workspace,
+    group: &latex::SyntaxNode,
+) -> Option<SignatureHelp> {
+    let parent = group.parent()?;
+    let environment = match parent.kind() {
+        latex::BEGIN => latex::Environment::cast(parent.parent()?)?,
+        latex::ENVIRONMENT => latex::Environment::cast(parent)?,
+        _ => return None,
+    };
+
+    let begin = environment.begin()?;
+    let name = begin.name()?;
+    if name.syntax() == group {
+        return None;
+    }
+
+    let arguments: Vec<_> = begin
+        .syntax()
+        .children()
+        .filter(|node| node != name.syntax() && is_group(node.kind()))
+        .chain(
+            environment
+                .syntax()
+                .children()
+                .skip(1)
+                .take_while(|node| is_group(node.kind())),
+        )
+        .collect();
+
+    if !arguments.contains(group) {
+        return None;
+    }
+
+    let name = name.key()?.to_string();
+    let signature = workspace
+        .documents_by_uri
+        .values()
+        .filter_map(|document| document.data.as_latex())
+        .flat_map(|data| data.extras.user_environments.iter())
+        .filter(|environment| environment.name == name)
+        .find_map(|environment| {
+            Signature::from_user_parameters(format!("\\begin{{{}}}", name), &environment.parameters)
+        })
+        .or_else(|| find_component_environment_signature(workspace, &name))?;
+
+    Some(signature.into_help(group, &arguments))
+}
+
+fn find_component_environment_signature(workspace: &Workspace, name: &str) -> Option<Signature> {
+    // The component database does not record environment arguments,
+    // so the parameters are taken from the language data instead.
+    COMPONENT_DATABASE
+        .linked_components(workspace)
+        .into_iter()
+        .flat_map(|component| component.environments.iter())
+        .find(|environment| *environment == name)?;
+
+    let parameters = LANGUAGE_DATA.find_environment_parameters(name)?;
+    Signature::from_environment_parameters(format!("\\begin{{{}}}", name), parameters)
+}
+
+fn is_group(kind: latex::SyntaxKind) -> bool {
+    matches!(
+        kind,
+        latex::CURLY_GROUP
+            | latex::CURLY_GROUP_WORD
+            | latex::CURLY_GROUP_WORD_LIST
+            | latex::CURLY_GROUP_COMMAND
+            | latex::CURLY_GROUP_KEY_VALUE
+            | latex::BRACK_GROUP
+            | latex::BRACK_GROUP_WORD
+            | latex::BRACK_GROUP_KEY_VALUE
+            | latex::MIXED_GROUP
+    )
+}
+
+fn is_brack_group(node: &latex::SyntaxNode) -> bool {
+    node.first_token()
+        .map_or(false, |token| token.kind() == latex::L_BRACK)
+}
+
+fn is_inside_group(node: &latex::SyntaxNode, offset: TextSize) -> bool {
+    let start = node.text_range().start();
+    let right_delim = node
+        .children_with_tokens()
+        .filter_map(|child| child.into_token())
+        .find(|token| {
+            matches!(
+                token.kind(),
+                latex::R_CURLY | latex::R_BRACK | latex::R_PAREN
+            )
+        });
+
+    start < offset && right_delim.map_or(true, |delim| offset <= delim.text_range().start())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::component_db::{ComponentArgument, ComponentParameter};
+
+    use super::*;
+
+    #[test]
+    fn test_component_command() {
+        let command = ComponentCommand {
+            name: "foo".into(),
+            image: None,
+            glyph: None,
+            parameters: vec![
+                ComponentParameter(Vec::new()),
+                ComponentParameter(vec![
+                    ComponentArgument {
+                        name: "left".into(),
+                        image: None,
+                    },
+                    ComponentArgument {
+                        name: "right".into(),
+                        image: None,
+                    },
+                ]),
+            ],
+        };
+
+        let signature = Signature::from_component_command("\\foo".into(), &command).unwrap();
+        let labels: Vec<_> = signature
+            .parameters
+            .iter()
+            .map(|param| (param.label.as_str(), param.is_optional))
+            .collect();
+
+        assert_eq!(signature.label, "\\foo");
+        assert_eq!(labels, vec![("{#1}", false), ("{left|right}", false)]);
+    }
+
+    #[test]
+    fn test_component_command_without_parameters() {
+        let command = ComponentCommand {
+            name: "foo".into(),
+            image: None,
+            glyph: None,
+            parameters: Vec::new(),
+        };
+
+        assert!(Signature::from_component_command("\\foo".into(), &command).is_none());
+    }
+}
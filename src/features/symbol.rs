@@ -1,19 +1,23 @@
 mod bibtex;
+mod definition;
 mod latex;
 mod project_order;
 mod types;
 
+pub(super) use self::project_order::ProjectOrdering;
+
 use std::{cmp::Reverse, sync::Arc};
 
 use lsp_types::{
     DocumentSymbolParams, DocumentSymbolResponse, PartialResultParams, SymbolInformation,
-    TextDocumentIdentifier, WorkDoneProgressParams, WorkspaceSymbolParams,
+    TextDocumentIdentifier, Url, WorkDoneProgressParams, WorkspaceSymbolParams,
 };
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::{ClientCapabilitiesExt, Workspace};
+use crate::{ClientCapabilitiesExt, DocumentData, Workspace};
 
 use self::{
-    bibtex::find_bibtex_symbols, latex::find_latex_symbols, project_order::ProjectOrdering,
+    bibtex::find_bibtex_symbols, definition::find_definition_symbols, latex::find_latex_symbols,
     types::InternalSymbol,
 };
 
@@ -55,48 +59,147 @@ struct WorkspaceSymbol {
     search_text: String,
 }
 
-#[must_use]
-pub fn find_workspace_symbols(
-    workspace: &Workspace,
-    params: &WorkspaceSymbolParams,
-) -> Vec<SymbolInformation> {
-    let mut symbols = Vec::new();
-
-    for document in workspace.documents_by_uri.values() {
-        let request = FeatureRequest {
-            uri: Arc::clone(&document.uri),
-            params: DocumentSymbolParams {
-                text_document: TextDocumentIdentifier::new(document.uri.as_ref().clone()),
-                partial_result_params: PartialResultParams::default(),
-                work_done_progress_params: WorkDoneProgressParams::default(),
-            },
-            workspace: workspace.slice(&document.uri),
-        };
-
-        let mut buf = Vec::new();
-        find_latex_symbols(&request, &mut buf);
-        find_bibtex_symbols(&request, &mut buf);
-        let mut new_buf = Vec::new();
+#[derive(Debug)]
+struct IndexedDocument {
+    data: DocumentData,
+    dependencies: Vec<Arc<Url>>,
+    symbols: Vec<WorkspaceSymbol>,
+}
 
-        for symbol in buf {
-            symbol.flatten(&mut new_buf);
+/// Compares the syntax trees instead of the texts,
+/// so that documents reparsed with different settings are indexed again.
+fn is_same_tree(left: &DocumentData, right: &DocumentData) -> bool {
+    match (left, right) {
+        (DocumentData::Latex(left), DocumentData::Latex(right)) => {
+            std::ptr::eq::<rowan::GreenNodeData>(&*left.green, &*right.green)
+        }
+        (DocumentData::Bibtex(left), DocumentData::Bibtex(right)) => {
+            std::ptr::eq::<rowan::GreenNodeData>(&*left.green, &*right.green)
+        }
+        (DocumentData::BuildLog(left), DocumentData::BuildLog(right)) => Arc::ptr_eq(left, right),
+        _ => false,
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WorkspaceSymbolIndex {
+    documents_by_uri: FxHashMap<Arc<Url>, IndexedDocument>,
+}
+
+impl WorkspaceSymbolIndex {
+    pub fn update(&mut self, workspace: &Workspace) {
+        let mut changed_uris: FxHashSet<_> = workspace
+            .documents_by_uri
+            .values()
+            .filter(|document| {
+                self.documents_by_uri
+                    .get(&document.uri)
+                    .map_or(true, |indexed| !is_same_tree(&indexed.data, &document.data))
+            })
+            .map(|document| Arc::clone(&document.uri))
+            .collect();
+
+        self.documents_by_uri.retain(|uri, _| {
+            let exists = workspace.documents_by_uri.contains_key(uri);
+            if !exists {
+                changed_uris.insert(Arc::clone(uri));
+            }
+
+            exists
+        });
+
+        if changed_uris.is_empty() {
+            return;
         }
 
-        for symbol in new_buf {
-            symbols.push(WorkspaceSymbol {
-                search_text: symbol.search_text(),
-                info: symbol.into_symbol_info(document.uri.as_ref().clone()),
-            });
+        let mut queue: Vec<_> = self
+            .documents_by_uri
+            .iter()
+            .filter(|(_, indexed)| {
+                indexed
+                    .dependencies
+                    .iter()
+                    .any(|uri| changed_uris.contains(uri))
+            })
+            .map(|(uri, _)| Arc::clone(uri))
+            .chain(changed_uris.iter().cloned())
+            .collect();
+
+        let mut visited = FxHashSet::default();
+        while let Some(uri) = queue.pop() {
+            if !visited.insert(Arc::clone(&uri)) {
+                continue;
+            }
+
+            let document = match workspace.documents_by_uri.get(&uri) {
+                Some(document) => document,
+                None => continue,
+            };
+
+            let request = FeatureRequest {
+                uri: Arc::clone(&document.uri),
+                params: DocumentSymbolParams {
+                    text_document: TextDocumentIdentifier::new(document.uri.as_ref().clone()),
+                    partial_result_params: PartialResultParams::default(),
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                },
+                workspace: workspace.slice(&document.uri),
+            };
+
+            let dependencies: Vec<_> = request.workspace.documents_by_uri.keys().cloned().collect();
+            if changed_uris.contains(&uri) {
+                queue.extend(dependencies.iter().cloned());
+            }
+
+            let mut buf = Vec::new();
+            find_latex_symbols(&request, &mut buf);
+            find_bibtex_symbols(&request, &mut buf);
+            let mut new_buf = Vec::new();
+
+            for symbol in buf {
+                symbol.flatten(&mut new_buf);
+            }
+
+            buf = new_buf;
+            find_definition_symbols(&request, &mut buf);
+
+            let symbols = buf
+                .into_iter()
+                .map(|symbol| WorkspaceSymbol {
+                    search_text: symbol.search_text(),
+                    info: symbol.into_symbol_info(document.uri.as_ref().clone()),
+                })
+                .collect();
+
+            self.documents_by_uri.insert(
+                uri,
+                IndexedDocument {
+                    data: document.data.clone(),
+                    dependencies,
+                    symbols,
+                },
+            );
         }
     }
+}
 
+#[must_use]
+pub fn find_workspace_symbols(
+    index: &WorkspaceSymbolIndex,
+    workspace: &Workspace,
+    params: &WorkspaceSymbolParams,
+) -> Vec<SymbolInformation> {
     let query_words: Vec<_> = params
         .query
         .split_whitespace()
         .map(str::to_lowercase)
         .collect();
     let mut filtered = Vec::new();
-    for symbol in symbols {
+    for symbol in index
+        .documents_by_uri
+        .values()
+        .flat_map(|indexed| &indexed.symbols)
+    {
         let mut included = true;
         for word in &query_words {
             if !symbol.search_text.contains(word) {
@@ -106,7 +209,7 @@ pub fn find_workspace_symbols(
         }
 
         if included {
-            filtered.push(symbol.info);
+            filtered.push(symbol.info.clone());
         }
     }
     sort_symbols(workspace, &mut filtered);
@@ -0,0 +1,110 @@
+use lsp_types::DocumentSymbolParams;
+use rowan::{ast::AstNode, TextRange};
+
+use crate::{
+    features::FeatureRequest, render_glossary_definition, render_label, syntax::latex, LineIndexExt,
+};
+
+use super::types::{InternalSymbol, InternalSymbolKind};
+
+pub fn find_definition_symbols(
+    request: &FeatureRequest<DocumentSymbolParams>,
+    buf: &mut Vec<InternalSymbol>,
+) -> Option<()> {
+    let main_document = request.main_document();
+    let data = main_document.data.as_latex()?;
+
+    for node in latex::SyntaxNode::new_root(data.green.clone()).descendants() {
+        if let Some((name, label, kind, full_range, selection_range)) = visit_label(request, &node)
+            .or_else(|| visit_command(&node))
+            .or_else(|| visit_environment(&node))
+            .or_else(|| visit_glossary_entry(request, node))
+        {
+            buf.push(InternalSymbol {
+                name,
+                label,
+                kind,
+                deprecated: false,
+                full_range: main_document.line_index.line_col_lsp_range(full_range),
+                selection_range: main_document.line_index.line_col_lsp_range(selection_range),
+                children: Vec::new(),
+            });
+        }
+    }
+
+    Some(())
+}
+
+type DefinitionSymbol = (
+    String,
+    Option<String>,
+    InternalSymbolKind,
+    TextRange,
+    TextRange,
+);
+
+fn visit_label(
+    request: &FeatureRequest<DocumentSymbolParams>,
+    node: &latex::SyntaxNode,
+) -> Option<DefinitionSymbol> {
+    let label = latex::LabelDefinition::cast(node.clone())?;
+    let key = label.name()?.key()?;
+    let name = match render_label(&request.workspace, &key.to_string(), Some(label))
+        .and_then(|rendered_label| rendered_label.number)
+    {
+        Some(number) => format!("{} ({})", key.to_string(), number),
+        None => key.to_string(),
+    };
+
+    let range = latex::small_range(&key);
+    Some((
+        name,
+        Some(key.to_string()),
+        InternalSymbolKind::Label,
+        range,
+        range,
+    ))
+}
+
+fn visit_command(node: &latex::SyntaxNode) -> Option<DefinitionSymbol> {
+    let definition = latex::CommandDefinition::cast(node.clone())?;
+    let command = definition.defined_command()?;
+    Some((
+        command.text().into(),
+        None,
+        InternalSymbolKind::Command,
+        latex::small_range(&definition),
+        command.text_range(),
+    ))
+}
+
+fn visit_environment(node: &latex::SyntaxNode) -> Option<DefinitionSymbol> {
+    let (full_range, key) = latex::EnvironmentDefinition::cast(node.clone())
+        .and_then(|def| Some((latex::small_range(&def), def.name()?.key()?)))
+        .or_else(|| {
+            let def = latex::TheoremDefinition::cast(node.clone())?;
+            Some((latex::small_range(&def), def.name()?.key()?))
+        })?;
+
+    Some((
+        key.to_string(),
+        None,
+        InternalSymbolKind::Environment,
+        full_range,
+        latex::small_range(&key),
+    ))
+}
+
+fn visit_glossary_entry(
+    request: &FeatureRequest<DocumentSymbolParams>,
+    node: latex::SyntaxNode,
+) -> Option<DefinitionSymbol> {
+    let (name, definition) = render_glossary_definition(&request.uri, node)?;
+    Some((
+        name,
+        None,
+        InternalSymbolKind::GlossaryEntry,
+        definition.range,
+        definition.name_range,
+    ))
+}
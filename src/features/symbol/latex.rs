@@ -14,12 +14,23 @@ use crate::{
 
 use super::types::{InternalSymbol, InternalSymbolKind};
 
+const BEAMER_BLOCKS: [(&str, &str); 4] = [
+    ("block", "Block"),
+    ("alertblock", "Alert Block"),
+    ("exampleblock", "Example Block"),
+    ("columns", "Columns"),
+];
+
 pub fn find_latex_symbols(
     request: &FeatureRequest<DocumentSymbolParams>,
     buf: &mut Vec<InternalSymbol>,
 ) -> Option<()> {
     let data = request.main_document().data.as_latex()?;
-    let mut context = Context { request, data };
+    let mut context = Context {
+        request,
+        data,
+        frame_number: 0,
+    };
 
     let root = context.data.green.clone();
     let mut symbols = visit(&mut context, latex::SyntaxNode::new_root(root));
@@ -30,6 +41,7 @@ pub fn find_latex_symbols(
 struct Context<'a> {
     request: &'a FeatureRequest<DocumentSymbolParams>,
     data: &'a LatexDocumentData,
+    frame_number: usize,
 }
 
 fn visit(context: &mut Context, node: latex::SyntaxNode) -> Vec<InternalSymbol> {
@@ -43,6 +55,7 @@ fn visit(context: &mut Context, node: latex::SyntaxNode) -> Vec<InternalSymbol>
         | latex::SUBPARAGRAPH => visit_section(context, node.clone()),
         latex::ENUM_ITEM => visit_enum_item(context, node.clone()),
         latex::EQUATION => visit_equation(context, node.clone()),
+        latex::GENERIC_COMMAND => visit_again_frame(context, node.clone()),
         latex::ENVIRONMENT => latex::Environment::cast(node.clone())
             .and_then(|env| env.begin())
             .and_then(|begin| begin.name())
@@ -61,6 +74,14 @@ fn visit(context: &mut Context, node: latex::SyntaxNode) -> Vec<InternalSymbol>
                     .any(|env| env == &name)
                 {
                     visit_enumeration(context, node.clone(), &name)
+                } else if name == "frame" {
+                    visit_frame(context, node.clone())
+                } else if let Some(kind) = BEAMER_BLOCKS
+                    .iter()
+                    .find(|(block, _)| *block == name)
+                    .map(|(_, kind)| *kind)
+                {
+                    visit_block(context, node.clone(), kind)
                 } else if let Ok(float_kind) = LabelledFloatKind::from_str(&name) {
                     visit_float(context, node.clone(), float_kind)
                 } else {
@@ -357,6 +378,114 @@ fn visit_float(
     Some(symbol)
 }
 
+fn visit_frame(context: &mut Context, node: latex::SyntaxNode) -> Option<InternalSymbol> {
+    let environment = latex::Environment::cast(node)?;
+    context.frame_number += 1;
+
+    let full_range = context
+        .request
+        .main_document()
+        .line_index
+        .line_col_lsp_range(latex::small_range(&environment));
+
+    let title = find_environment_title(&environment)
+        .filter(|title| !title.is_empty())
+        .or_else(|| {
+            environment
+                .syntax()
+                .descendants()
+                .filter_map(latex::GenericCommand::cast)
+                .find(|command| {
+                    command
+                        .name()
+                        .map_or(false, |name| name.text() == "\\frametitle")
+                })?
+                .syntax()
+                .children()
+                .find_map(latex::CurlyGroup::cast)?
+                .content_text()
+                .filter(|title| !title.is_empty())
+        });
+
+    let name = title.unwrap_or_else(|| format!("Frame {}", context.frame_number));
+    Some(InternalSymbol {
+        name,
+        label: None,
+        kind: InternalSymbolKind::Frame,
+        deprecated: false,
+        full_range,
+        selection_range: full_range,
+        children: Vec::new(),
+    })
+}
+
+fn visit_again_frame(context: &mut Context, node: latex::SyntaxNode) -> Option<InternalSymbol> {
+    let command = latex::GenericCommand::cast(node)?;
+    if command.name()?.text() != "\\againframe" {
+        return None;
+    }
+
+    let label = command
+        .syntax()
+        .children()
+        .find_map(latex::CurlyGroup::cast)?
+        .content_text()?;
+
+    let full_range = context
+        .request
+        .main_document()
+        .line_index
+        .line_col_lsp_range(latex::small_range(&command));
+
+    Some(InternalSymbol {
+        name: format!("Frame ({})", label),
+        label: Some(label),
+        kind: InternalSymbolKind::Frame,
+        deprecated: false,
+        full_range,
+        selection_range: full_range,
+        children: Vec::new(),
+    })
+}
+
+fn visit_block(
+    context: &mut Context,
+    node: latex::SyntaxNode,
+    block_kind: &str,
+) -> Option<InternalSymbol> {
+    let environment = latex::Environment::cast(node)?;
+    let full_range = context
+        .request
+        .main_document()
+        .line_index
+        .line_col_lsp_range(latex::small_range(&environment));
+
+    let name = match find_environment_title(&environment) {
+        Some(title) if !title.is_empty() => format!("{}: {}", block_kind, title),
+        _ => block_kind.to_string(),
+    };
+
+    Some(InternalSymbol {
+        name,
+        label: None,
+        kind: InternalSymbolKind::Block,
+        deprecated: false,
+        full_range,
+        selection_range: full_range,
+        children: Vec::new(),
+    })
+}
+
+fn find_environment_title(environment: &latex::Environment) -> Option<String> {
+    let begin = environment.begin()?;
+    begin
+        .syntax()
+        .siblings(rowan::Direction::Next)
+        .nth(1)
+        .and_then(latex::CurlyGroup::cast)?
+        .content_text()
+}
+
 fn visit_theorem(
     context: &mut Context,
     node: latex::SyntaxNode,
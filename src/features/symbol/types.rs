@@ -16,6 +16,12 @@ pub enum InternalSymbolKind {
     Entry(BibtexEntryTypeCategory),
     Field,
     String,
+    Label,
+    Command,
+    Environment,
+    GlossaryEntry,
+    Frame,
+    Block,
 }
 
 impl InternalSymbolKind {
@@ -32,6 +38,12 @@ impl InternalSymbolKind {
             Self::Entry(category) => Structure::Entry(category).symbol_kind(),
             Self::Field => Structure::Field.symbol_kind(),
             Self::String => Structure::Entry(BibtexEntryTypeCategory::String).symbol_kind(),
+            Self::Label => Structure::Label.symbol_kind(),
+            Self::Command => Structure::Command.symbol_kind(),
+            Self::Environment => Structure::Environment.symbol_kind(),
+            Self::GlossaryEntry => Structure::GlossaryEntry.symbol_kind(),
+            Self::Frame => Structure::Section.symbol_kind(),
+            Self::Block => Structure::Environment.symbol_kind(),
         }
     }
 }
@@ -62,8 +74,20 @@ impl InternalSymbol {
             InternalSymbolKind::Entry(_) => "bibtex entry",
             InternalSymbolKind::Field => "bibtex field",
             InternalSymbolKind::String => "bibtex string",
+            InternalSymbolKind::Label => "latex label",
+            InternalSymbolKind::Command => "latex command",
+            InternalSymbolKind::Environment => "latex environment",
+            InternalSymbolKind::GlossaryEntry => "latex glossary",
+            InternalSymbolKind::Frame => "latex beamer frame",
+            InternalSymbolKind::Block => "latex beamer block",
         };
-        format!("{} {}", kind, self.name).to_lowercase()
+        // Labels are matched by their key only, so that type queries do not find them.
+        let name = match self.kind {
+            InternalSymbolKind::Label => self.label.as_deref().unwrap_or(&self.name),
+            _ => &self.name,
+        };
+
+        format!("{} {}", kind, name).to_lowercase()
     }
 
     pub fn flatten(mut self, buffer: &mut Vec<Self>) {
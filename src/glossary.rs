@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use lsp_types::Url;
+use rowan::{ast::AstNode, TextRange};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    syntax::latex::{self, HasCurly, HasKeyValueBody},
+    Workspace,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GlossaryDefinition {
+    pub uri: Arc<Url>,
+    pub range: TextRange,
+    pub name_range: TextRange,
+    pub short: Option<String>,
+    pub long: Option<String>,
+    pub description: Option<String>,
+}
+
+impl GlossaryDefinition {
+    #[must_use]
+    pub fn reference(&self) -> Option<String> {
+        let mut text = match (&self.short, &self.long) {
+            (Some(short), Some(long)) => format!("{} ({})", short, long),
+            (Some(text), None) | (None, Some(text)) => text.clone(),
+            (None, None) => String::new(),
+        };
+
+        if let Some(description) = &self.description {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+
+            text.push_str(description);
+        }
+
+        Some(text).filter(|text| !text.is_empty())
+    }
+}
+
+/// Finds the names of the glossary entries and acronyms defined in the given project.
+/// The project is expected to be a slice of the workspace, like the one of a feature request.
+#[must_use]
+pub fn find_glossary_names(workspace: &Workspace) -> FxHashSet<String> {
+    workspace
+        .documents_by_uri
+        .values()
+        .filter_map(|document| document.data.as_latex())
+        .flat_map(|data| data.extras.glossary_names.iter().cloned())
+        .collect()
+}
+
+/// Finds the definition of a glossary entry or acronym in the given project.
+/// The scope matches the one of `find_glossary_names`.
+pub fn find_glossary_definition(workspace: &Workspace, name: &str) -> Option<GlossaryDefinition> {
+    workspace.documents_by_uri.values().find_map(|document| {
+        let data = document.data.as_latex()?;
+        latex::SyntaxNode::new_root(data.green.clone())
+            .descendants()
+            .find_map(|node| {
+                render_glossary_definition(&document.uri, node)
+                    .filter(|(def_name, _)| def_name == name)
+            })
+            .map(|(_, definition)| definition)
+    })
+}
+
+pub fn render_glossary_definition(
+    uri: &Arc<Url>,
+    node: latex::SyntaxNode,
+) -> Option<(String, GlossaryDefinition)> {
+    if let Some(entry) = latex::GlossaryEntryDefinition::cast(node.clone()) {
+        let name = entry.name()?.key()?;
+        let options = entry.options();
+        let definition = GlossaryDefinition {
+            uri: Arc::clone(uri),
+            range: latex::small_range(&entry),
+            name_range: latex::small_range(&name),
+            short: options
+                .as_ref()
+                .and_then(|options| find_option(options, "name")),
+            long: None,
+            description: options.and_then(|options| find_option(&options, "description")),
+        };
+
+        Some((name.to_string(), definition))
+    } else if let Some(acronym) = latex::AcronymDefinition::cast(node.clone()) {
+        let name = acronym.name()?.key()?;
+        let definition = GlossaryDefinition {
+            uri: Arc::clone(uri),
+            range: latex::small_range(&acronym),
+            name_range: latex::small_range(&name),
+            short: acronym.short().and_then(|group| group.content_text()),
+            long: acronym.long().and_then(|group| group.content_text()),
+            description: None,
+        };
+
+        Some((name.to_string(), definition))
+    } else {
+        let acronym = latex::AcronymDeclaration::cast(node)?;
+        let name = acronym.name()?.key()?;
+        let options = acronym.options();
+        let definition = GlossaryDefinition {
+            uri: Arc::clone(uri),
+            range: latex::small_range(&acronym),
+            name_range: latex::small_range(&name),
+            short: options
+                .as_ref()
+                .and_then(|options| find_option(options, "short")),
+            long: options
+                .as_ref()
+                .and_then(|options| find_option(options, "long")),
+            description: options.and_then(|options| find_option(&options, "extra")),
+        };
+
+        Some((name.to_string(), definition))
+    }
+}
+
+fn find_option(options: &latex::CurlyGroupKeyValue, key: &str) -> Option<String> {
+    let value = options
+        .body()?
+        .pairs()
+        .find(|pair| pair.key().map_or(false, |name| name.to_string() == key))?
+        .value()?;
+
+    let text = value.syntax().text().to_string();
+    let text = text.trim();
+    let text = text
+        .strip_prefix('{')
+        .and_then(|text| text.strip_suffix('}'))
+        .unwrap_or(text)
+        .trim();
+
+    Some(text.to_string()).filter(|text| !text.is_empty())
+}
@@ -28,16 +28,62 @@ pub struct BibtexFieldDoc {
     pub documentation: String,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamedColor {
+    pub name: String,
+    pub rgb: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyValueOption {
+    pub name: String,
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentOptions {
+    pub name: String,
+    pub options: Vec<KeyValueOption>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvironmentParameter {
+    pub name: String,
+    #[serde(default)]
+    pub optional: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvironmentParameters {
+    pub name: String,
+    pub parameters: Vec<EnvironmentParameter>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LanguageData {
-    pub colors: Vec<String>,
+    pub colors: Vec<NamedColor>,
     pub entry_types: Vec<BibtexEntryTypeDoc>,
     pub fields: Vec<BibtexFieldDoc>,
     pub pgf_libraries: Vec<String>,
     pub tikz_libraries: Vec<String>,
     pub math_environments: Vec<String>,
+    pub math_components: Vec<String>,
     pub enum_environments: Vec<String>,
+    pub environment_parameters: Vec<EnvironmentParameters>,
+    pub graphics_options: Vec<KeyValueOption>,
+    // The component database is generated from the TeX distribution and does not
+    // record options, so package and class options are curated here like the TikZ ones.
+    pub package_options: Vec<ComponentOptions>,
+    pub tikz_options: Vec<ComponentOptions>,
+    pub language_ids: Vec<String>,
+    pub publication_states: Vec<String>,
 }
 
 impl LanguageData {
@@ -55,6 +101,35 @@ impl LanguageData {
             .and_then(|ty| ty.documentation.as_ref().map(AsRef::as_ref))
     }
 
+    #[must_use]
+    pub fn find_color(&self, name: &str) -> Option<&NamedColor> {
+        self.colors.iter().find(|color| color.name == name)
+    }
+
+    #[must_use]
+    pub fn find_environment_parameters(&self, name: &str) -> Option<&[EnvironmentParameter]> {
+        self.environment_parameters
+            .iter()
+            .find(|environment| environment.name == name)
+            .map(|environment| environment.parameters.as_slice())
+    }
+
+    #[must_use]
+    pub fn find_package_options(&self, file_name: &str) -> Option<&[KeyValueOption]> {
+        self.package_options
+            .iter()
+            .find(|package| package.name == file_name)
+            .map(|package| package.options.as_slice())
+    }
+
+    #[must_use]
+    pub fn find_tikz_options(&self, library: &str) -> Option<&[KeyValueOption]> {
+        self.tikz_options
+            .iter()
+            .find(|tikz| tikz.name == library)
+            .map(|tikz| tikz.options.as_slice())
+    }
+
     #[must_use]
     pub fn field_documentation(&self, name: &str) -> Option<&str> {
         self.fields
@@ -8,6 +8,7 @@ pub mod distro;
 mod document;
 mod environment;
 pub mod features;
+mod glossary;
 mod label;
 mod lang_data;
 mod language;
@@ -24,6 +25,7 @@ pub use self::{
     capabilities::ClientCapabilitiesExt,
     document::*,
     environment::Environment,
+    glossary::*,
     label::*,
     lang_data::*,
     language::DocumentLanguage,
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
@@ -30,6 +31,15 @@ pub struct Options {
 
     #[serde(default)]
     pub forward_search: ForwardSearchOptions,
+
+    #[serde(default)]
+    pub inlay_hints: InlayHintOptions,
+
+    #[serde(default)]
+    pub completion: CompletionOptions,
+
+    #[serde(default)]
+    pub experimental: ExperimentalOptions,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
@@ -134,3 +144,57 @@ pub struct ForwardSearchOptions {
     pub executable: Option<String>,
     pub args: Option<Vec<String>>,
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintOptions {
+    #[serde(default)]
+    pub citations: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionOptions {
+    pub figure_label_prefix: Option<String>,
+}
+
+impl CompletionOptions {
+    #[must_use]
+    pub fn figure_label_prefix(&self) -> &str {
+        self.figure_label_prefix.as_deref().unwrap_or("fig:")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SectionLevel {
+    Part,
+    Chapter,
+    Section,
+    Subsection,
+    Subsubsection,
+    Paragraph,
+    Subparagraph,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperimentalOptions {
+    #[serde(default)]
+    pub label_definition_commands: Vec<String>,
+
+    #[serde(default)]
+    pub label_reference_commands: Vec<String>,
+
+    #[serde(default)]
+    pub citation_commands: Vec<String>,
+
+    #[serde(default)]
+    pub include_commands: Vec<String>,
+
+    #[serde(default)]
+    pub section_commands: FxHashMap<String, SectionLevel>,
+
+    #[serde(default)]
+    pub glossary_entry_reference_commands: Vec<String>,
+}
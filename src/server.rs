@@ -17,11 +17,14 @@ use crate::{
     dispatch::{NotificationDispatcher, RequestDispatcher},
     distro::Distribution,
     features::{
-        execute_command, find_all_references, find_document_highlights, find_document_links,
-        find_document_symbols, find_foldings, find_hover, find_workspace_symbols,
-        format_source_code, goto_definition, prepare_rename_all, rename_all, BuildEngine,
-        BuildParams, BuildResult, BuildStatus, FeatureRequest, ForwardSearchResult,
-        ForwardSearchStatus,
+        execute_command, find_all_references, find_code_lenses, find_color_presentations,
+        find_document_colors, find_document_highlights, find_document_links, find_document_symbols,
+        find_foldings, find_hover, find_incoming_calls, find_inlay_hints,
+        find_linked_editing_ranges, find_outgoing_calls, find_project_tree, find_selection_ranges,
+        find_signature_help, find_workspace_symbols, format_source_code, goto_definition,
+        prepare_call_hierarchy, prepare_rename_all, rename_all, BuildEngine, BuildParams,
+        BuildResult, BuildStatus, FeatureRequest, ForwardSearchResult, ForwardSearchStatus,
+        ProjectTreeNode, ProjectTreeParams, WorkspaceSymbolIndex,
     },
     req_queue::{IncomingData, ReqQueue},
     ClientCapabilitiesExt, DocumentLanguage, Environment, LineIndex, LineIndexExt, Options,
@@ -31,7 +34,7 @@ use crate::{
 #[derive(Debug)]
 enum InternalMessage {
     SetDistro(Distribution),
-    SetOptions(Options),
+    SetOptions(Arc<Options>),
 }
 
 #[derive(Clone)]
@@ -46,6 +49,7 @@ pub struct Server {
     pool: Arc<Mutex<ThreadPool>>,
     load_resolver: bool,
     build_engine: Arc<BuildEngine>,
+    symbol_index: Arc<Mutex<WorkspaceSymbolIndex>>,
 }
 
 impl Server {
@@ -78,6 +82,7 @@ impl Server {
             pool: Arc::new(Mutex::new(threadpool::Builder::new().build())),
             load_resolver,
             build_engine: Arc::default(),
+            symbol_index: Arc::default(),
         }
     }
 
@@ -127,6 +132,19 @@ impl Server {
                 work_done_progress_options: WorkDoneProgressOptions::default(),
             })),
             document_highlight_provider: Some(OneOf::Left(true)),
+            inlay_hint_provider: Some(OneOf::Left(true)),
+            linked_editing_range_provider: Some(LinkedEditingRangeServerCapabilities::Simple(true)),
+            call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+            code_lens_provider: Some(CodeLensOptions {
+                resolve_provider: Some(false),
+            }),
+            selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+            color_provider: Some(ColorProviderCapability::Simple(true)),
+            signature_help_provider: Some(SignatureHelpOptions {
+                trigger_characters: Some(vec!["{".into(), "[".into()]),
+                retrigger_characters: None,
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+            }),
             document_formatting_provider: Some(OneOf::Left(true)),
             execute_command_provider: Some(ExecuteCommandOptions {
                 commands: vec![
@@ -152,6 +170,7 @@ impl Server {
                 name: "TexLab".to_owned(),
                 version: Some(env!("CARGO_PKG_VERSION").to_owned()),
             }),
+            offset_encoding: None,
         };
         self.connection
             .initialize_finish(id, serde_json::to_value(result)?)?;
@@ -305,7 +324,7 @@ impl Server {
                 };
 
                 self.internal_tx
-                    .send(InternalMessage::SetOptions(options))
+                    .send(InternalMessage::SetOptions(Arc::new(options)))
                     .unwrap();
             }
             Err(why) => {
@@ -327,8 +346,10 @@ impl Server {
     }
 
     fn did_change_watched_files(&mut self, params: DidChangeWatchedFilesParams) -> Result<()> {
+        let mut has_aux_changes = false;
         for change in params.changes {
             if let Ok(path) = change.uri.to_file_path() {
+                has_aux_changes |= path.extension().map_or(false, |ext| ext == "aux");
                 match change.typ {
                     FileChangeType::CREATED | FileChangeType::CHANGED => {
                         self.workspace.reload(path)?;
@@ -341,6 +362,28 @@ impl Server {
             }
         }
 
+        if has_aux_changes
+            && self
+                .workspace
+                .environment
+                .client_capabilities
+                .has_inlay_hint_refresh_support()
+        {
+            self.spawn(move |server| {
+                if let Err(why) = send_request::<InlayHintRefreshRequest>(
+                    &server.req_queue,
+                    &server.connection.sender,
+                    (),
+                ) {
+                    error!(
+                        "Failed to send \"{}\" request: {}",
+                        InlayHintRefreshRequest::METHOD,
+                        why
+                    );
+                }
+            });
+        }
+
         Ok(())
     }
 
@@ -538,6 +581,48 @@ impl Server {
         Ok(())
     }
 
+    fn code_lens(&self, id: RequestId, params: CodeLensParams) -> Result<()> {
+        let uri = Arc::new(params.text_document.uri.clone());
+        self.handle_feature_request(id, params, uri, find_code_lenses)?;
+        Ok(())
+    }
+
+    fn prepare_call_hierarchy(
+        &self,
+        id: RequestId,
+        params: CallHierarchyPrepareParams,
+    ) -> Result<()> {
+        let uri = Arc::new(
+            params
+                .text_document_position_params
+                .text_document
+                .uri
+                .clone(),
+        );
+        self.handle_feature_request(id, params, uri, prepare_call_hierarchy)?;
+        Ok(())
+    }
+
+    fn incoming_calls(
+        &self,
+        id: RequestId,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> Result<()> {
+        let uri = Arc::new(params.item.uri.clone());
+        self.handle_feature_request(id, params, uri, find_incoming_calls)?;
+        Ok(())
+    }
+
+    fn outgoing_calls(
+        &self,
+        id: RequestId,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> Result<()> {
+        let uri = Arc::new(params.item.uri.clone());
+        self.handle_feature_request(id, params, uri, find_outgoing_calls)?;
+        Ok(())
+    }
+
     fn document_symbols(&self, id: RequestId, params: DocumentSymbolParams) -> Result<()> {
         let uri = Arc::new(params.text_document.uri.clone());
         self.handle_feature_request(id, params, uri, find_document_symbols)?;
@@ -546,7 +631,10 @@ impl Server {
 
     fn workspace_symbols(&self, id: RequestId, params: WorkspaceSymbolParams) -> Result<()> {
         self.spawn(move |server| {
-            let result = find_workspace_symbols(&server.workspace, &params);
+            let mut index = server.symbol_index.lock().unwrap();
+            index.update(&server.workspace);
+            let result = find_workspace_symbols(&index, &server.workspace, &params);
+            drop(index);
             server
                 .connection
                 .sender
@@ -677,6 +765,54 @@ impl Server {
         Ok(())
     }
 
+    fn signature_help(&self, id: RequestId, params: SignatureHelpParams) -> Result<()> {
+        let uri = Arc::new(
+            params
+                .text_document_position_params
+                .text_document
+                .uri
+                .clone(),
+        );
+        self.handle_feature_request(id, params, uri, find_signature_help)?;
+        Ok(())
+    }
+
+    fn inlay_hints(&self, id: RequestId, params: InlayHintParams) -> Result<()> {
+        let uri = Arc::new(params.text_document.uri.clone());
+        self.handle_feature_request(id, params, uri, find_inlay_hints)?;
+        Ok(())
+    }
+
+    fn linked_editing_range(&self, id: RequestId, params: LinkedEditingRangeParams) -> Result<()> {
+        let uri = Arc::new(
+            params
+                .text_document_position_params
+                .text_document
+                .uri
+                .clone(),
+        );
+        self.handle_feature_request(id, params, uri, find_linked_editing_ranges)?;
+        Ok(())
+    }
+
+    fn selection_range(&self, id: RequestId, params: SelectionRangeParams) -> Result<()> {
+        let uri = Arc::new(params.text_document.uri.clone());
+        self.handle_feature_request(id, params, uri, find_selection_ranges)?;
+        Ok(())
+    }
+
+    fn document_color(&self, id: RequestId, params: DocumentColorParams) -> Result<()> {
+        let uri = Arc::new(params.text_document.uri.clone());
+        self.handle_feature_request(id, params, uri, find_document_colors)?;
+        Ok(())
+    }
+
+    fn color_presentation(&self, id: RequestId, params: ColorPresentationParams) -> Result<()> {
+        let uri = Arc::new(params.text_document.uri.clone());
+        self.handle_feature_request(id, params, uri, find_color_presentations)?;
+        Ok(())
+    }
+
     fn formatting(&self, id: RequestId, params: DocumentFormattingParams) -> Result<()> {
         let uri = Arc::new(params.text_document.uri.clone());
         self.handle_feature_request(id, params, uri, format_source_code)?;
@@ -737,6 +873,12 @@ impl Server {
         Ok(())
     }
 
+    fn project_tree(&self, id: RequestId, params: ProjectTreeParams) -> Result<()> {
+        let uri = Arc::new(params.text_document.uri.clone());
+        self.handle_feature_request(id, params, uri, find_project_tree)?;
+        Ok(())
+    }
+
     fn reparse_all(&mut self) -> Result<()> {
         for document in self
             .workspace
@@ -768,6 +910,16 @@ impl Server {
                             self.register_incoming_request(request.id.clone());
                             if let Some(response) = RequestDispatcher::new(request)
                                 .on::<DocumentLinkRequest, _>(|id, params| self.document_link(id, params))?
+                                .on::<CodeLensRequest, _>(|id, params| self.code_lens(id, params))?
+                                .on::<CallHierarchyPrepare, _>(|id, params| {
+                                    self.prepare_call_hierarchy(id, params)
+                                })?
+                                .on::<CallHierarchyIncomingCalls, _>(|id, params| {
+                                    self.incoming_calls(id, params)
+                                })?
+                                .on::<CallHierarchyOutgoingCalls, _>(|id, params| {
+                                    self.outgoing_calls(id, params)
+                                })?
                                 .on::<FoldingRangeRequest, _>(|id, params| self.folding_range(id, params))?
                                 .on::<References, _>(|id, params| self.references(id, params))?
                                 .on::<HoverRequest, _>(|id, params| self.hover(id, params))?
@@ -793,11 +945,30 @@ impl Server {
                                 .on::<DocumentHighlightRequest, _>(|id, params| {
                                     self.document_highlight(id, params)
                                 })?
+                                .on::<SignatureHelpRequest, _>(|id, params| {
+                                    self.signature_help(id, params)
+                                })?
+                                .on::<InlayHintRequest, _>(|id, params| self.inlay_hints(id, params))?
+                                .on::<LinkedEditingRange, _>(|id, params| {
+                                    self.linked_editing_range(id, params)
+                                })?
+                                .on::<SelectionRangeRequest, _>(|id, params| {
+                                    self.selection_range(id, params)
+                                })?
+                                .on::<DocumentColor, _>(|id, params| {
+                                    self.document_color(id, params)
+                                })?
+                                .on::<ColorPresentationRequest, _>(|id, params| {
+                                    self.color_presentation(id, params)
+                                })?
                                 .on::<Formatting, _>(|id, params| self.formatting(id, params))?
                                 .on::<BuildRequest, _>(|id, params| self.build(id, params))?
                                 .on::<ForwardSearchRequest, _>(|id, params| {
                                     self.forward_search(id, params)
                                 })?
+                                .on::<ProjectTreeRequest, _>(|id, params| {
+                                    self.project_tree(id, params)
+                                })?
                                 .on::<ExecuteCommand,_>(|id, params| self.execute_command(id, params))?
                                 .on::<SemanticTokensRangeRequest, _>(|id, params| {
                                     self.semantic_tokens_range(id, params)
@@ -841,7 +1012,7 @@ impl Server {
                             self.reparse_all()?;
                         }
                         InternalMessage::SetOptions(options) => {
-                            self.workspace.environment.options = Arc::new(options);
+                            self.workspace.environment.options = options;
                             self.reparse_all()?;
                         }
                     };
@@ -866,8 +1037,9 @@ fn create_static_debouncer(
 ) -> DiagnosticsDebouncer {
     let sender = conn.sender.clone();
     DiagnosticsDebouncer::launch(move |workspace, document| {
+        let project = workspace.slice(&document.uri);
         let mut manager = manager.lock().unwrap();
-        manager.update_static(&workspace, Arc::clone(&document.uri));
+        manager.update_static(&project, Arc::clone(&document.uri));
         if let Err(why) = publish_diagnostics(&sender, &workspace, &manager) {
             warn!("Failed to publish diagnostics: {}", why);
         }
@@ -941,3 +1113,13 @@ impl lsp_types::request::Request for ForwardSearchRequest {
 
     const METHOD: &'static str = "textDocument/forwardSearch";
 }
+
+struct ProjectTreeRequest;
+
+impl lsp_types::request::Request for ProjectTreeRequest {
+    type Params = ProjectTreeParams;
+
+    type Result = Option<ProjectTreeNode>;
+
+    const METHOD: &'static str = "texlab/projectTree";
+}
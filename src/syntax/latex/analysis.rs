@@ -2,6 +2,7 @@ mod command;
 mod distro_file;
 mod environment;
 mod explicit_link;
+mod glossary;
 mod graphics_path;
 mod implicit_link;
 mod label_name;
@@ -14,8 +15,9 @@ use crate::syntax::latex;
 pub use self::types::*;
 use self::{
     command::{analyze_command, analyze_command_definition},
-    environment::analyze_begin,
+    environment::{analyze_begin, analyze_environment_definition},
     explicit_link::{analyze_import, analyze_include},
+    glossary::analyze_glossary_definition,
     graphics_path::analyze_graphics_path,
     implicit_link::analyze_implicit_links,
     label_name::analyze_label_name,
@@ -29,11 +31,13 @@ pub fn analyze(context: &mut LatexAnalyzerContext, root: &latex::SyntaxNode) {
         analyze_command(context, node.clone())
             .or_else(|| analyze_command_definition(context, node.clone()))
             .or_else(|| analyze_begin(context, node.clone()))
+            .or_else(|| analyze_environment_definition(context, node.clone()))
             .or_else(|| analyze_include(context, node.clone()))
             .or_else(|| analyze_import(context, node.clone()))
             .or_else(|| analyze_label_name(context, node.clone()))
             .or_else(|| analyze_label_number(context, node.clone()))
             .or_else(|| analyze_theorem_definition(context, node.clone()))
+            .or_else(|| analyze_glossary_definition(context, node.clone()))
             .or_else(|| analyze_graphics_path(context, node));
     }
     context.extras.has_document_environment = context.extras.environment_names.contains("document");
@@ -1,8 +1,10 @@
+use std::{iter::Peekable, str::Chars};
+
 use rowan::ast::AstNode;
 
-use crate::syntax::latex;
+use crate::syntax::latex::{self, HasBrack, HasCurly};
 
-use super::LatexAnalyzerContext;
+use super::{LatexAnalyzerContext, UserCommand, UserParameter, UserParameterKind};
 
 pub fn analyze_command(context: &mut LatexAnalyzerContext, node: latex::SyntaxNode) -> Option<()> {
     let command = latex::GenericCommand::cast(node)?;
@@ -18,9 +20,163 @@ pub fn analyze_command_definition(
     node: latex::SyntaxNode,
 ) -> Option<()> {
     let definition = latex::CommandDefinition::cast(node)?;
-    context
-        .extras
-        .command_names
-        .insert(definition.name()?.command()?.text().into());
+    let name = definition.defined_command()?;
+    context.extras.command_names.insert(name.text().into());
+
+    // `\DeclareMathOperator` never takes arguments of its own.
+    let is_math_operator = definition.command()?.kind() == latex::MATH_OPERATOR_NAME;
+    let parameters = match definition.parameter_text() {
+        _ if is_math_operator => Vec::new(),
+        Some(text) => text
+            .matches('#')
+            .enumerate()
+            .map(|(i, _)| UserParameter {
+                kind: UserParameterKind::Mandatory,
+                default: None,
+                number: i + 1,
+            })
+            .collect(),
+        None => find_parameters(
+            definition.argument_count(),
+            definition.default_argument(),
+            definition.argument_spec(),
+        ),
+    };
+
+    context.extras.user_commands.push(UserCommand {
+        name: name.text().into(),
+        parameters,
+    });
+
     Some(())
 }
+
+pub(super) fn find_parameters(
+    argument_count: Option<latex::BrackGroupWord>,
+    default_argument: Option<latex::BrackGroup>,
+    argument_spec: Option<latex::CurlyGroup>,
+) -> Vec<UserParameter> {
+    if let Some(spec) = argument_spec {
+        return spec
+            .content_text()
+            .map(|spec| parse_argument_spec(&spec))
+            .unwrap_or_default();
+    }
+
+    let count = argument_count
+        .and_then(|group| group.key())
+        .and_then(|key| key.to_string().parse().ok())
+        .unwrap_or(0);
+
+    let default = default_argument.and_then(|group| group.content_text());
+    (0..count)
+        .map(|i| match &default {
+            Some(default) if i == 0 => UserParameter {
+                kind: UserParameterKind::Optional,
+                default: Some(default.clone()),
+                number: i + 1,
+            },
+            _ => UserParameter {
+                kind: UserParameterKind::Mandatory,
+                default: None,
+                number: i + 1,
+            },
+        })
+        .collect()
+}
+
+fn parse_argument_spec(spec: &str) -> Vec<UserParameter> {
+    let mut parameters = Vec::new();
+    let mut number = 0;
+    let mut chars = spec.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            'm' | 'v' => {
+                number += 1;
+                parameters.push(UserParameter {
+                    kind: UserParameterKind::Mandatory,
+                    default: None,
+                    number,
+                });
+            }
+            'o' => {
+                number += 1;
+                parameters.push(UserParameter {
+                    kind: UserParameterKind::Optional,
+                    default: None,
+                    number,
+                });
+            }
+            'O' => {
+                number += 1;
+                parameters.push(UserParameter {
+                    kind: UserParameterKind::Optional,
+                    default: read_braced(&mut chars),
+                    number,
+                });
+            }
+            '>' => {
+                read_braced(&mut chars);
+            }
+            'e' => {
+                number += count_tokens(read_braced(&mut chars));
+            }
+            'E' => {
+                number += count_tokens(read_braced(&mut chars));
+                read_braced(&mut chars);
+            }
+            's' | 'g' | 'l' | 'b' => {
+                number += 1;
+            }
+            'G' | 'u' => {
+                read_braced(&mut chars);
+                number += 1;
+            }
+            't' => {
+                chars.next();
+                number += 1;
+            }
+            'd' | 'r' => {
+                chars.next();
+                chars.next();
+                number += 1;
+            }
+            'D' | 'R' => {
+                chars.next();
+                chars.next();
+                read_braced(&mut chars);
+                number += 1;
+            }
+            _ => {}
+        };
+    }
+
+    parameters
+}
+
+// Each token of an embellishment is a separate argument.
+fn count_tokens(tokens: Option<String>) -> usize {
+    tokens.map_or(0, |tokens| {
+        tokens.chars().filter(|c| !c.is_whitespace()).count()
+    })
+}
+
+fn read_braced(chars: &mut Peekable<Chars>) -> Option<String> {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    chars.next_if_eq(&'{')?;
+
+    let mut text = String::new();
+    let mut depth = 0;
+    for c in chars {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => break,
+            '}' => depth -= 1,
+            _ => {}
+        };
+
+        text.push(c);
+    }
+
+    Some(text.trim().into())
+}
@@ -2,7 +2,7 @@ use rowan::ast::AstNode;
 
 use crate::syntax::latex;
 
-use super::LatexAnalyzerContext;
+use super::{command::find_parameters, LatexAnalyzerContext, UserEnvironment};
 
 pub fn analyze_begin(context: &mut LatexAnalyzerContext, node: latex::SyntaxNode) -> Option<()> {
     let begin = latex::Begin::cast(node)?;
@@ -11,3 +11,23 @@ pub fn analyze_begin(context: &mut LatexAnalyzerContext, node: latex::SyntaxNode
     extras.environment_names.insert(name);
     Some(())
 }
+
+pub fn analyze_environment_definition(
+    context: &mut LatexAnalyzerContext,
+    node: latex::SyntaxNode,
+) -> Option<()> {
+    let definition = latex::EnvironmentDefinition::cast(node)?;
+    let name = definition.name()?.key()?.to_string();
+    let parameters = find_parameters(
+        definition.argument_count(),
+        definition.default_argument(),
+        definition.argument_spec(),
+    );
+
+    let extras = &mut context.extras;
+    extras.environment_names.insert(name.clone());
+    extras
+        .user_environments
+        .push(UserEnvironment { name, parameters });
+    Some(())
+}
@@ -0,0 +1,19 @@
+use rowan::ast::AstNode;
+
+use crate::syntax::latex;
+
+use super::LatexAnalyzerContext;
+
+pub fn analyze_glossary_definition(
+    context: &mut LatexAnalyzerContext,
+    node: latex::SyntaxNode,
+) -> Option<()> {
+    let name = latex::GlossaryEntryDefinition::cast(node.clone())
+        .and_then(|entry| entry.name())
+        .or_else(|| latex::AcronymDefinition::cast(node.clone())?.name())
+        .or_else(|| latex::AcronymDeclaration::cast(node)?.name())?
+        .key()?;
+
+    context.extras.glossary_names.insert(name.to_string());
+    Some(())
+}
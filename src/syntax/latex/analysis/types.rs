@@ -21,11 +21,14 @@ pub struct Extras {
     pub explicit_links: Vec<ExplicitLink>,
     pub has_document_environment: bool,
     pub command_names: FxHashSet<SmolStr>,
+    pub user_commands: Vec<UserCommand>,
+    pub user_environments: Vec<UserEnvironment>,
     pub environment_names: FxHashSet<String>,
     pub label_names: Vec<LabelName>,
     pub label_numbers_by_name: FxHashMap<String, String>,
     pub theorem_environments: Vec<TheoremEnvironment>,
     pub graphics_paths: FxHashSet<String>,
+    pub glossary_names: FxHashSet<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default, Hash)]
@@ -67,6 +70,33 @@ pub struct TheoremEnvironment {
     pub description: String,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum UserParameterKind {
+    Mandatory,
+    Optional,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct UserParameter {
+    pub kind: UserParameterKind,
+    pub default: Option<String>,
+    /// The position of the parameter in the definition (`#1`, `#2`, ...),
+    /// which also counts the arguments that are not recorded as parameters.
+    pub number: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Hash)]
+pub struct UserCommand {
+    pub name: SmolStr,
+    pub parameters: Vec<UserParameter>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Hash)]
+pub struct UserEnvironment {
+    pub name: String,
+    pub parameters: Vec<UserParameter>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Default, Hash)]
 pub struct LabelName {
     pub text: SmolStr,
@@ -555,7 +555,74 @@ impl CommandDefinition {
         self.syntax().children().find_map(CurlyGroupCommand::cast)
     }
 
+    pub fn defined_command(&self) -> Option<SyntaxToken> {
+        match self.name() {
+            Some(name) => name.command(),
+            None => self
+                .syntax()
+                .children_with_tokens()
+                .filter_map(|child| child.into_token())
+                .skip(1)
+                .find(|token| token.kind().is_command_name()),
+        }
+    }
+
+    pub fn parameter_text(&self) -> Option<String> {
+        self.command()
+            .filter(|command| command.kind() == PLAIN_DEFINITION_NAME)?;
+        let text = self
+            .syntax()
+            .children_with_tokens()
+            .filter_map(|child| child.into_token())
+            .filter(|token| !matches!(token.kind(), LINE_BREAK | WHITESPACE | COMMENT))
+            .skip(2)
+            .map(|token| token.text().to_string())
+            .collect();
+
+        Some(text)
+    }
+
+    pub fn argument_count(&self) -> Option<BrackGroupWord> {
+        self.syntax().children().find_map(BrackGroupWord::cast)
+    }
+
+    pub fn default_argument(&self) -> Option<BrackGroup> {
+        self.syntax().children().find_map(BrackGroup::cast)
+    }
+
+    pub fn argument_spec(&self) -> Option<CurlyGroup> {
+        self.command()
+            .filter(|command| command.kind() == DOCUMENT_COMMAND_DEFINITION_NAME)?;
+        self.syntax().children().find_map(CurlyGroup::cast)
+    }
+
     pub fn implementation(&self) -> Option<CurlyGroup> {
+        self.syntax().children().filter_map(CurlyGroup::cast).last()
+    }
+}
+
+cst_node!(EnvironmentDefinition, ENVIRONMENT_DEFINITION);
+
+impl EnvironmentDefinition {
+    pub fn command(&self) -> Option<SyntaxToken> {
+        self.syntax().first_token()
+    }
+
+    pub fn name(&self) -> Option<CurlyGroupWord> {
+        self.syntax().children().find_map(CurlyGroupWord::cast)
+    }
+
+    pub fn argument_count(&self) -> Option<BrackGroupWord> {
+        self.syntax().children().find_map(BrackGroupWord::cast)
+    }
+
+    pub fn default_argument(&self) -> Option<BrackGroup> {
+        self.syntax().children().find_map(BrackGroup::cast)
+    }
+
+    pub fn argument_spec(&self) -> Option<CurlyGroup> {
+        self.command()
+            .filter(|command| command.kind() == DOCUMENT_ENVIRONMENT_DEFINITION_NAME)?;
         self.syntax().children().find_map(CurlyGroup::cast)
     }
 }
@@ -566,6 +633,10 @@ impl AcronymReference {
     pub fn command(&self) -> Option<SyntaxToken> {
         self.syntax().first_token()
     }
+
+    pub fn name(&self) -> Option<CurlyGroupWord> {
+        self.syntax().children().find_map(CurlyGroupWord::cast)
+    }
 }
 
 cst_node!(AcronymDefinition, ACRONYM_DEFINITION);
@@ -578,6 +649,14 @@ impl AcronymDefinition {
     pub fn name(&self) -> Option<CurlyGroupWord> {
         self.syntax().children().find_map(CurlyGroupWord::cast)
     }
+
+    pub fn short(&self) -> Option<CurlyGroup> {
+        self.syntax().children().find_map(CurlyGroup::cast)
+    }
+
+    pub fn long(&self) -> Option<CurlyGroup> {
+        self.syntax().children().filter_map(CurlyGroup::cast).nth(1)
+    }
 }
 
 cst_node!(AcronymDeclaration, ACRONYM_DECLARATION);
@@ -590,6 +669,10 @@ impl AcronymDeclaration {
     pub fn name(&self) -> Option<CurlyGroupWord> {
         self.syntax().children().find_map(CurlyGroupWord::cast)
     }
+
+    pub fn options(&self) -> Option<CurlyGroupKeyValue> {
+        self.syntax().children().find_map(CurlyGroupKeyValue::cast)
+    }
 }
 
 cst_node!(ColorDefinition, COLOR_DEFINITION);
@@ -661,6 +744,10 @@ impl GlossaryEntryDefinition {
     pub fn name(&self) -> Option<CurlyGroupWord> {
         self.syntax().children().find_map(CurlyGroupWord::cast)
     }
+
+    pub fn options(&self) -> Option<CurlyGroupKeyValue> {
+        self.syntax().children().find_map(CurlyGroupKeyValue::cast)
+    }
 }
 
 cst_node!(TikzLibraryImport, TIKZ_LIBRARY_IMPORT);
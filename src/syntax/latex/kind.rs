@@ -49,6 +49,9 @@ pub enum SyntaxKind {
     LABEL_REFERENCE_RANGE_NAME,
     LABEL_NUMBER_NAME,
     COMMAND_DEFINITION_NAME,
+    DOCUMENT_COMMAND_DEFINITION_NAME,
+    PLAIN_DEFINITION_NAME,
+    LET_DEFINITION_NAME,
     MATH_OPERATOR_NAME,
     GLOSSARY_ENTRY_DEFINITION_NAME,
     GLOSSARY_ENTRY_REFERENCE_NAME,
@@ -61,6 +64,7 @@ pub enum SyntaxKind {
     COLOR_SET_DEFINITION_NAME,
     TIKZ_LIBRARY_IMPORT_NAME,
     ENVIRONMENT_DEFINITION_NAME,
+    DOCUMENT_ENVIRONMENT_DEFINITION_NAME,
     BEGIN_BLOCK_COMMENT_NAME,
     END_BLOCK_COMMENT_NAME,
     GRAPHICS_PATH_NAME,
@@ -164,6 +168,9 @@ impl SyntaxKind {
                 | LABEL_REFERENCE_RANGE_NAME
                 | LABEL_NUMBER_NAME
                 | COMMAND_DEFINITION_NAME
+                | DOCUMENT_COMMAND_DEFINITION_NAME
+                | PLAIN_DEFINITION_NAME
+                | LET_DEFINITION_NAME
                 | MATH_OPERATOR_NAME
                 | GLOSSARY_ENTRY_DEFINITION_NAME
                 | GLOSSARY_ENTRY_REFERENCE_NAME
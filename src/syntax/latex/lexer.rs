@@ -1,5 +1,7 @@
 use logos::Logos;
 
+use crate::{ExperimentalOptions, SectionLevel};
+
 use super::kind::SyntaxKind;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Logos)]
@@ -46,7 +48,8 @@ enum RootToken {
     #[regex(r"\$\$?")]
     Dollar,
 
-    #[regex(r"\\([^\r\n]|[@a-zA-Z:_]+\*?)?")]
+    // Negated classes match single bytes, so non-ASCII characters are listed separately.
+    #[regex(r"\\([^\r\n\x{80}-\x{10FFFF}]|[\x{80}-\x{10FFFF}]|[@a-zA-Z:_]+\*?)?")]
     CommandName,
 
     #[token("\\iffalse")]
@@ -244,8 +247,25 @@ enum CommandNameToken {
     #[token("\\renewcommand*")]
     #[token("\\DeclareRobustCommand")]
     #[token("\\DeclareRobustCommand*")]
+    #[token("\\providecommand")]
+    #[token("\\providecommand*")]
     CommandDefinition,
 
+    #[token("\\NewDocumentCommand")]
+    #[token("\\RenewDocumentCommand")]
+    #[token("\\ProvideDocumentCommand")]
+    #[token("\\DeclareDocumentCommand")]
+    DocumentCommandDefinition,
+
+    #[token("\\def")]
+    #[token("\\edef")]
+    #[token("\\gdef")]
+    #[token("\\xdef")]
+    PlainDefinition,
+
+    #[token("\\let")]
+    LetDefinition,
+
     #[token("\\DeclareMathOperator")]
     #[token("\\DeclareMathOperator*")]
     MathOperator,
@@ -375,15 +395,23 @@ enum CommandNameToken {
     #[token("\\newenvironment*")]
     #[token("\\renewenvironment")]
     #[token("\\renewenvironment*")]
+    #[token("\\provideenvironment")]
+    #[token("\\provideenvironment*")]
     EnvironmentDefinition,
 
+    #[token("\\NewDocumentEnvironment")]
+    #[token("\\RenewDocumentEnvironment")]
+    #[token("\\ProvideDocumentEnvironment")]
+    #[token("\\DeclareDocumentEnvironment")]
+    DocumentEnvironmentDefinition,
+
     #[token("\\graphicspath")]
     GraphicsPath,
 
     #[token("\\fi")]
     EndBlockComment,
 
-    #[regex(r"\\([^\r\n]|[@a-zA-Z:_]+\*?)?")]
+    #[regex(r"\\([^\r\n\x{80}-\x{10FFFF}]|[\x{80}-\x{10FFFF}]|[@a-zA-Z:_]+\*?)?")]
     #[error]
     Generic,
 }
@@ -393,7 +421,7 @@ enum BlockCommentToken {
     #[token("\\fi")]
     End,
 
-    #[regex(r"\\([^\r\n]|[@a-zA-Z:_]+\*?)?")]
+    #[regex(r"\\([^\r\n\x{80}-\x{10FFFF}]|[\x{80}-\x{10FFFF}]|[@a-zA-Z:_]+\*?)?")]
     #[regex(r"[^\\]+")]
     #[error]
     Verbatim,
@@ -408,7 +436,7 @@ enum VerbatimEnvironmentToken {
     #[token("\\end{pycode}")]
     End,
 
-    #[regex(r"\\([^\r\n]|[@a-zA-Z:_]+\*?)?")]
+    #[regex(r"\\([^\r\n\x{80}-\x{10FFFF}]|[\x{80}-\x{10FFFF}]|[@a-zA-Z:_]+\*?)?")]
     #[regex(r"[^\\]+")]
     #[error]
     Verbatim,
@@ -420,9 +448,15 @@ pub struct Lexer<'a> {
 }
 
 impl<'a> Lexer<'a> {
-    pub fn new(input: &'a str) -> Self {
+    pub fn new(input: &'a str, options: &ExperimentalOptions) -> Self {
         let mut tokens = Vec::new();
         tokenize(input, &mut tokens);
+        for (kind, text) in &mut tokens {
+            if *kind == SyntaxKind::GENERIC_COMMAND_NAME {
+                *kind = classify_custom_command(text, options).unwrap_or(*kind);
+            }
+        }
+
         tokens.reverse();
         Self { tokens }
     }
@@ -431,11 +465,53 @@ impl<'a> Lexer<'a> {
         self.tokens.last().map(|(kind, _)| *kind)
     }
 
+    pub fn peek_text(&self) -> Option<&'a str> {
+        self.tokens.last().map(|(_, text)| *text)
+    }
+
     pub fn eat(&mut self) -> Option<(SyntaxKind, &'a str)> {
         self.tokens.pop()
     }
 }
 
+fn classify_custom_command(text: &str, options: &ExperimentalOptions) -> Option<SyntaxKind> {
+    let name = &text[1..];
+    let contains = |commands: &[String]| {
+        commands
+            .iter()
+            .any(|command| command.strip_prefix('\\').unwrap_or(command) == name)
+    };
+
+    if contains(&options.label_definition_commands) {
+        Some(SyntaxKind::LABEL_DEFINITION_NAME)
+    } else if contains(&options.label_reference_commands) {
+        Some(SyntaxKind::LABEL_REFERENCE_NAME)
+    } else if contains(&options.citation_commands) {
+        Some(SyntaxKind::CITATION_NAME)
+    } else if contains(&options.include_commands) {
+        Some(SyntaxKind::LATEX_INCLUDE_NAME)
+    } else if let Some(level) = options
+        .section_commands
+        .iter()
+        .find(|(command, _)| command.strip_prefix('\\').unwrap_or(command) == name)
+        .map(|(_, level)| *level)
+    {
+        Some(match level {
+            SectionLevel::Part => SyntaxKind::PART_NAME,
+            SectionLevel::Chapter => SyntaxKind::CHAPTER_NAME,
+            SectionLevel::Section => SyntaxKind::SECTION_NAME,
+            SectionLevel::Subsection => SyntaxKind::SUBSECTION_NAME,
+            SectionLevel::Subsubsection => SyntaxKind::SUBSUBSECTION_NAME,
+            SectionLevel::Paragraph => SyntaxKind::PARAGRAPH_NAME,
+            SectionLevel::Subparagraph => SyntaxKind::SUBPARAGRAPH_NAME,
+        })
+    } else if contains(&options.glossary_entry_reference_commands) {
+        Some(SyntaxKind::GLOSSARY_ENTRY_REFERENCE_NAME)
+    } else {
+        None
+    }
+}
+
 fn tokenize<'a>(input: &'a str, tokens: &mut Vec<(SyntaxKind, &'a str)>) {
     let mut lexer = RootToken::lexer(input);
     while let Some(kind) = lexer.next() {
@@ -536,6 +612,9 @@ fn tokenize_command_name(text: &str) -> SyntaxKind {
         CommandNameToken::LabelReferenceRange => SyntaxKind::LABEL_REFERENCE_RANGE_NAME,
         CommandNameToken::LabelNumber => SyntaxKind::LABEL_NUMBER_NAME,
         CommandNameToken::CommandDefinition => SyntaxKind::COMMAND_DEFINITION_NAME,
+        CommandNameToken::DocumentCommandDefinition => SyntaxKind::DOCUMENT_COMMAND_DEFINITION_NAME,
+        CommandNameToken::PlainDefinition => SyntaxKind::PLAIN_DEFINITION_NAME,
+        CommandNameToken::LetDefinition => SyntaxKind::LET_DEFINITION_NAME,
         CommandNameToken::MathOperator => SyntaxKind::MATH_OPERATOR_NAME,
         CommandNameToken::GlossaryEntryDefinition => SyntaxKind::GLOSSARY_ENTRY_DEFINITION_NAME,
         CommandNameToken::GlossaryEntryReference => SyntaxKind::GLOSSARY_ENTRY_REFERENCE_NAME,
@@ -548,6 +627,9 @@ fn tokenize_command_name(text: &str) -> SyntaxKind {
         CommandNameToken::ColorSetDefinition => SyntaxKind::COLOR_SET_DEFINITION_NAME,
         CommandNameToken::TikzLibraryImport => SyntaxKind::TIKZ_LIBRARY_IMPORT_NAME,
         CommandNameToken::EnvironmentDefinition => SyntaxKind::ENVIRONMENT_DEFINITION_NAME,
+        CommandNameToken::DocumentEnvironmentDefinition => {
+            SyntaxKind::DOCUMENT_ENVIRONMENT_DEFINITION_NAME
+        }
         CommandNameToken::EndBlockComment => SyntaxKind::END_BLOCK_COMMENT_NAME,
         CommandNameToken::GraphicsPath => SyntaxKind::GRAPHICS_PATH_NAME,
         CommandNameToken::Generic => SyntaxKind::GENERIC_COMMAND_NAME,
@@ -608,11 +690,39 @@ mod tests {
     use super::*;
 
     fn verify(text: &str) -> Vec<(SyntaxKind, &str)> {
-        let mut tokens = Lexer::new(text).tokens;
+        verify_with_options(text, &ExperimentalOptions::default())
+    }
+
+    fn verify_with_options<'a>(
+        text: &'a str,
+        options: &ExperimentalOptions,
+    ) -> Vec<(SyntaxKind, &'a str)> {
+        let mut tokens = Lexer::new(text, options).tokens;
         tokens.reverse();
         tokens
     }
 
+    #[test]
+    fn test_custom_commands() {
+        let options = ExperimentalOptions {
+            label_reference_commands: vec!["figref".into()],
+            citation_commands: vec!["\\citeauthoryear".into()],
+            include_commands: vec!["inputsection".into()],
+            section_commands: [
+                ("mychapter".to_string(), SectionLevel::Chapter),
+                ("\\mysection".to_string(), SectionLevel::Section),
+            ]
+            .into_iter()
+            .collect(),
+            ..ExperimentalOptions::default()
+        };
+
+        assert_debug_snapshot!(verify_with_options(
+            r#"\figref{a} \citeauthoryear{b} \inputsection{c} \mychapter{d} \mysection{e} \other"#,
+            &options
+        ));
+    }
+
     #[test]
     fn test_empty() {
         assert_debug_snapshot!(verify(r#""#));
@@ -628,6 +738,11 @@ mod tests {
         assert_debug_snapshot!(verify(r#"\newcommand{\id}[1]{#1}"#));
     }
 
+    #[test]
+    fn test_command_with_unicode() {
+        assert_debug_snapshot!(verify(r#"\α \alpha"#));
+    }
+
     #[test]
     fn test_command_with_star() {
         assert_debug_snapshot!(verify(r#"\section*{Foo}"#));
@@ -1,5 +1,7 @@
 use rowan::{GreenNode, GreenNodeBuilder};
 
+use crate::ExperimentalOptions;
+
 use super::{
     lexer::Lexer,
     SyntaxKind::{self, *},
@@ -32,9 +34,9 @@ struct Parser<'a> {
 }
 
 impl<'a> Parser<'a> {
-    pub fn new(text: &'a str) -> Self {
+    pub fn new(text: &'a str, options: &ExperimentalOptions) -> Self {
         Self {
-            lexer: Lexer::new(text),
+            lexer: Lexer::new(text, options),
             builder: GreenNodeBuilder::new(),
         }
     }
@@ -137,6 +139,9 @@ impl<'a> Parser<'a> {
             LABEL_REFERENCE_RANGE_NAME => self.label_reference_range(),
             LABEL_NUMBER_NAME => self.label_number(),
             COMMAND_DEFINITION_NAME => self.command_definition(),
+            DOCUMENT_COMMAND_DEFINITION_NAME => self.document_command_definition(),
+            PLAIN_DEFINITION_NAME => self.plain_definition(),
+            LET_DEFINITION_NAME => self.let_definition(),
             MATH_OPERATOR_NAME => self.math_operator(),
             GLOSSARY_ENTRY_DEFINITION_NAME => self.glossary_entry_definition(),
             GLOSSARY_ENTRY_REFERENCE_NAME => self.glossary_entry_reference(),
@@ -149,6 +154,7 @@ impl<'a> Parser<'a> {
             COLOR_SET_DEFINITION_NAME => self.color_set_definition(),
             TIKZ_LIBRARY_IMPORT_NAME => self.tikz_library_import(),
             ENVIRONMENT_DEFINITION_NAME => self.environment_definition(),
+            DOCUMENT_ENVIRONMENT_DEFINITION_NAME => self.document_environment_definition(),
             BEGIN_BLOCK_COMMENT_NAME => self.block_comment(),
             END_BLOCK_COMMENT_NAME => self.generic_command(),
             GRAPHICS_PATH_NAME => self.graphics_path(),
@@ -1057,6 +1063,117 @@ impl<'a> Parser<'a> {
         self.builder.finish_node();
     }
 
+    fn document_command_definition(&mut self) {
+        self.builder.start_node(COMMAND_DEFINITION.into());
+        self.eat();
+        self.trivia();
+
+        if self.lexer.peek() == Some(L_CURLY) {
+            self.curly_group_command();
+        } else {
+            self.builder.token(MISSING.into(), "");
+        }
+
+        if self.lexer.peek() == Some(L_CURLY) {
+            self.curly_group();
+        } else {
+            self.builder.token(MISSING.into(), "");
+        }
+
+        if self.lexer.peek() == Some(L_CURLY) {
+            self.curly_group_impl();
+        } else {
+            self.builder.token(MISSING.into(), "");
+        }
+
+        self.builder.finish_node();
+    }
+
+    fn plain_definition(&mut self) {
+        let checkpoint = self.builder.checkpoint();
+        self.eat();
+        self.trivia();
+        if self.is_dynamic_command_name() {
+            self.builder
+                .start_node_at(checkpoint, GENERIC_COMMAND.into());
+            self.builder.finish_node();
+            return;
+        }
+
+        self.builder
+            .start_node_at(checkpoint, COMMAND_DEFINITION.into());
+
+        if self.peek().map_or(false, |kind| kind.is_command_name()) {
+            self.eat();
+            self.trivia();
+        } else {
+            self.builder.token(MISSING.into(), "");
+        }
+
+        if self
+            .lexer
+            .peek_text()
+            .map_or(false, |text| text.starts_with('#'))
+        {
+            while let Some(
+                WORD | GENERIC_COMMAND_NAME | L_BRACK | R_BRACK | L_PAREN | R_PAREN | COMMA
+                | EQUALITY_SIGN | WHITESPACE | LINE_BREAK | COMMENT,
+            ) = self.peek()
+            {
+                self.eat();
+            }
+        }
+
+        if self.peek() == Some(L_CURLY) {
+            self.curly_group_impl();
+        } else {
+            self.builder.token(MISSING.into(), "");
+        }
+
+        self.builder.finish_node();
+    }
+
+    fn let_definition(&mut self) {
+        let checkpoint = self.builder.checkpoint();
+        self.eat();
+        self.trivia();
+        if self.is_dynamic_command_name() {
+            self.builder
+                .start_node_at(checkpoint, GENERIC_COMMAND.into());
+            self.builder.finish_node();
+            return;
+        }
+
+        self.builder
+            .start_node_at(checkpoint, COMMAND_DEFINITION.into());
+
+        if self.peek().map_or(false, |kind| kind.is_command_name()) {
+            self.eat();
+            self.trivia();
+        } else {
+            self.builder.token(MISSING.into(), "");
+        }
+
+        if self.peek() == Some(EQUALITY_SIGN) {
+            self.eat();
+            self.trivia();
+        }
+
+        if self.peek().map_or(false, |kind| kind.is_command_name()) {
+            self.eat();
+        } else {
+            self.builder.token(MISSING.into(), "");
+        }
+
+        self.builder.finish_node();
+    }
+
+    /// Names built with `\csname` or `\expandafter` are only known after expansion,
+    /// so they are not recorded as definitions.
+    fn is_dynamic_command_name(&self) -> bool {
+        matches!(self.lexer.peek_text(), Some("\\csname" | "\\expandafter"))
+    }
+
     fn math_operator(&mut self) {
         self.builder.start_node(MATH_OPERATOR.into());
         self.eat();
@@ -1316,6 +1433,34 @@ impl<'a> Parser<'a> {
         self.builder.finish_node();
     }
 
+    fn document_environment_definition(&mut self) {
+        self.builder.start_node(ENVIRONMENT_DEFINITION.into());
+        self.eat();
+        self.trivia();
+
+        if self.lexer.peek() == Some(L_CURLY) {
+            self.curly_group_word();
+        } else {
+            self.builder.token(MISSING.into(), "");
+        }
+
+        if self.lexer.peek() == Some(L_CURLY) {
+            self.curly_group();
+        } else {
+            self.builder.token(MISSING.into(), "");
+        }
+
+        for _ in 0..2 {
+            if self.lexer.peek() == Some(L_CURLY) {
+                self.curly_group_without_environments();
+            } else {
+                self.builder.token(MISSING.into(), "");
+            }
+        }
+
+        self.builder.finish_node();
+    }
+
     fn graphics_path(&mut self) {
         self.builder.start_node(GRAPHICS_PATH.into());
         self.eat();
@@ -1337,8 +1482,8 @@ impl<'a> Parser<'a> {
     }
 }
 
-pub fn parse(text: &str) -> Parse {
-    Parser::new(text).parse()
+pub fn parse(text: &str, options: &ExperimentalOptions) -> Parse {
+    Parser::new(text, options).parse()
 }
 
 #[cfg(test)]
@@ -1350,7 +1495,13 @@ mod tests {
     use super::*;
 
     fn setup(text: &str) -> latex::SyntaxNode {
-        latex::SyntaxNode::new_root(parse(&text.trim().replace('\r', "")).green)
+        latex::SyntaxNode::new_root(
+            parse(
+                &text.trim().replace('\r', ""),
+                &ExperimentalOptions::default(),
+            )
+            .green,
+        )
     }
 
     #[test]
@@ -1753,6 +1904,51 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_document_command_definition() {
+        assert_debug_snapshot!(setup(r#"\NewDocumentCommand{\foo}{s m O{bar}}{#2}"#));
+    }
+
+    #[test]
+    fn test_plain_definition() {
+        assert_debug_snapshot!(setup(r#"\def\foo#1#2{#1 #2}"#));
+    }
+
+    #[test]
+    fn test_plain_definition_spaced_parameters() {
+        assert_debug_snapshot!(setup(r#"\def\foo#1 #2{#1 #2}"#));
+    }
+
+    #[test]
+    fn test_plain_definition_delimited() {
+        assert_debug_snapshot!(setup(r#"\gdef\foo#1\@nil{#1}"#));
+    }
+
+    #[test]
+    fn test_plain_definition_incomplete() {
+        assert_debug_snapshot!(setup(r#"\def\foo bar \baz{qux}"#));
+    }
+
+    #[test]
+    fn test_plain_definition_at_name() {
+        assert_debug_snapshot!(setup(r#"\gdef\@foo@bar{baz}"#));
+    }
+
+    #[test]
+    fn test_plain_definition_csname() {
+        assert_debug_snapshot!(setup(r#"\expandafter\def\csname foo\endcsname{bar}"#));
+    }
+
+    #[test]
+    fn test_let_definition_expandafter() {
+        assert_debug_snapshot!(setup(r#"\let\expandafter\foo\csname bar\endcsname"#));
+    }
+
+    #[test]
+    fn test_let_definition() {
+        assert_debug_snapshot!(setup(r#"\let\foo=\bar \baz"#));
+    }
+
     #[test]
     fn test_math_operator_simple() {
         assert_debug_snapshot!(setup(r#"\DeclareMathOperator{\foo}{foo}"#));
@@ -1880,6 +2076,18 @@ mod tests {
         assert_debug_snapshot!(setup(r#"\newenvironment{foo}[1][default]{begin}{end}"#));
     }
 
+    #[test]
+    fn test_environment_definition_provide() {
+        assert_debug_snapshot!(setup(r#"\provideenvironment{foo}{begin}{end}"#));
+    }
+
+    #[test]
+    fn test_document_environment_definition() {
+        assert_debug_snapshot!(setup(
+            r#"\NewDocumentEnvironment{foo}{m O{default}}{\begin{bar}}{\end{bar}}"#
+        ));
+    }
+
     #[test]
     fn test_acronym_declaration() {
         assert_debug_snapshot!(setup(
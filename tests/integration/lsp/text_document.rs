@@ -1,11 +1,19 @@
+mod call_hierarchy;
+mod code_lens;
 #[cfg(feature = "completion")]
 mod completion;
 mod definition;
+mod diagnostics;
+mod document_color;
 mod document_highlight;
 mod document_link;
 mod document_symbol;
 mod folding_range;
 mod formatting;
 mod hover;
+mod inlay_hint;
+mod linked_editing_range;
 mod references;
 mod rename;
+mod selection_range;
+mod signature_help;
@@ -0,0 +1,150 @@
+use anyhow::Result;
+use assert_unordered::assert_eq_unordered;
+use lsp_types::{
+    request::{CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare},
+    CallHierarchyIncomingCallsParams, CallHierarchyItem, CallHierarchyOutgoingCallsParams,
+    CallHierarchyPrepareParams, ClientCapabilities, Range, TextDocumentIdentifier,
+    TextDocumentPositionParams, Url,
+};
+
+use crate::lsp::{client::Client, fixture};
+
+fn prepare(client: &mut Client, fixture: &fixture::Fixture) -> Result<CallHierarchyItem> {
+    let cursor = fixture.cursor.unwrap();
+    let items = client
+        .request::<CallHierarchyPrepare>(CallHierarchyPrepareParams {
+            text_document_position_params: TextDocumentPositionParams::new(
+                TextDocumentIdentifier::new(client.uri(cursor.name)?),
+                cursor.position,
+            ),
+            work_done_progress_params: Default::default(),
+        })?
+        .unwrap_or_default();
+
+    assert_eq!(items.len(), 1);
+    Ok(items.into_iter().next().unwrap())
+}
+
+fn expected_calls(client: &Client, fixture: &fixture::Fixture) -> Result<Vec<(Url, Vec<Range>)>> {
+    let mut expected = Vec::new();
+    for ranges in fixture.ranges.values() {
+        expected.push((client.uri(ranges[&2].name)?, vec![ranges[&1].range]));
+    }
+
+    Ok(expected)
+}
+
+fn check_incoming(fixture: &str) -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+
+    let fixture = fixture::parse(fixture);
+    for file in &fixture.files {
+        client.open(file.name, file.lang, file.text.clone())?;
+    }
+
+    let item = prepare(&mut client, &fixture)?;
+    let actual_calls: Vec<_> = client
+        .request::<CallHierarchyIncomingCalls>(CallHierarchyIncomingCallsParams {
+            item,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|call| (call.from.uri, call.from_ranges))
+        .collect();
+
+    let expected_calls = expected_calls(&client, &fixture)?;
+    client.shutdown()?;
+
+    assert_eq_unordered!(actual_calls, expected_calls);
+    Ok(())
+}
+
+fn check_outgoing(fixture: &str) -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+
+    let fixture = fixture::parse(fixture);
+    for file in &fixture.files {
+        client.open(file.name, file.lang, file.text.clone())?;
+    }
+
+    let item = prepare(&mut client, &fixture)?;
+    let actual_calls: Vec<_> = client
+        .request::<CallHierarchyOutgoingCalls>(CallHierarchyOutgoingCallsParams {
+            item,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|call| (call.to.uri, call.from_ranges))
+        .collect();
+
+    let expected_calls = expected_calls(&client, &fixture)?;
+    client.shutdown()?;
+
+    assert_eq_unordered!(actual_calls, expected_calls);
+    Ok(())
+}
+
+#[test]
+fn incoming() -> Result<()> {
+    check_incoming(
+        r#"
+%TEX foo.tex
+%SRC \input{baz}
+%1.1        ^^^
+%1.2 
+
+%TEX bar.tex
+%SRC \include{baz}
+%2.1          ^^^
+%2.2 
+
+%TEX baz.tex
+%SRC Baz
+%CUR ^
+"#,
+    )
+}
+
+#[test]
+fn incoming_from_link() -> Result<()> {
+    check_incoming(
+        r#"
+%TEX foo.tex
+%SRC \input{bar}
+%CUR         ^
+%1.1        ^^^
+%1.2 
+
+%TEX bar.tex
+%SRC Bar
+"#,
+    )
+}
+
+#[test]
+fn outgoing() -> Result<()> {
+    check_outgoing(
+        r#"
+%TEX foo.tex
+%SRC \input{bar}
+%CUR ^
+%1.1        ^^^
+%SRC \bibliography{baz}
+%2.1               ^^^
+
+%TEX bar.tex
+%SRC Bar
+%1.2 
+
+%BIB baz.bib
+%SRC @article{baz,}
+%2.2 
+"#,
+    )
+}
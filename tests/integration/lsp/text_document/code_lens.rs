@@ -0,0 +1,93 @@
+use anyhow::Result;
+use assert_unordered::assert_eq_unordered;
+use lsp_types::{
+    request::CodeLensRequest, ClientCapabilities, CodeLensParams, TextDocumentIdentifier,
+};
+
+use crate::lsp::{client::Client, fixture};
+
+fn check(fixture: &str, expected_titles: &[&str]) -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+
+    let fixture = fixture::parse(fixture);
+    for file in fixture.files {
+        client.open(file.name, file.lang, file.text)?;
+    }
+
+    let actual_lenses: Vec<_> = client
+        .request::<CodeLensRequest>(CodeLensParams {
+            text_document: TextDocumentIdentifier::new(client.uri(fixture.cursor.unwrap().name)?),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|lens| (lens.range, lens.command.unwrap().title))
+        .collect();
+
+    client.shutdown()?;
+
+    let expected_lenses: Vec<_> = fixture
+        .ranges
+        .values()
+        .zip(expected_titles)
+        .map(|(ranges, title)| (ranges[&1].range, title.to_string()))
+        .collect();
+
+    assert_eq_unordered!(actual_lenses, expected_lenses);
+    Ok(())
+}
+
+#[test]
+fn label() -> Result<()> {
+    check(
+        r#"
+%TEX foo.tex
+%SRC \label{foo}
+%CUR ^
+%1.1        ^^^
+%SRC \label{bar}
+%2.1        ^^^
+%SRC \ref{foo} \cref{foo}
+
+%TEX bar.tex
+%SRC \input{foo}
+%SRC \eqref{foo}
+"#,
+        &["3 references", "unused"],
+    )
+}
+
+#[test]
+fn entry() -> Result<()> {
+    check(
+        r#"
+%BIB main.bib
+%SRC @article{foo,}
+%CUR ^
+%1.1          ^^^
+%SRC @article{bar,}
+%2.1          ^^^
+
+%TEX main.tex
+%SRC \addbibresource{main.bib}
+%SRC \cite{foo}
+"#,
+        &["1 reference", "unused"],
+    )
+}
+
+#[test]
+fn command() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foo}{foo}
+%CUR ^
+%1.1             ^^^^
+%SRC \foo \foo
+"#,
+        &["2 references"],
+    )
+}
@@ -2,15 +2,23 @@ use anyhow::Result;
 use insta::assert_json_snapshot;
 use lsp_types::{
     request::{Completion, ResolveCompletionItem},
-    ClientCapabilities, CompletionItem, CompletionList, CompletionParams, CompletionResponse,
-    CompletionTextEdit, Range,
+    ClientCapabilities, CompletionClientCapabilities, CompletionItem, CompletionItemCapability,
+    CompletionList, CompletionParams, CompletionResponse, CompletionTextEdit, Range,
+    TextDocumentClientCapabilities,
 };
 
 use crate::lsp::{client::Client, fixture};
 
 fn complete(fixture: &str) -> Result<Vec<CompletionItem>, anyhow::Error> {
+    complete_with_capabilities(fixture, ClientCapabilities::default())
+}
+
+fn complete_with_capabilities(
+    fixture: &str,
+    client_capabilities: ClientCapabilities,
+) -> Result<Vec<CompletionItem>, anyhow::Error> {
     let mut client = Client::spawn()?;
-    client.initialize(ClientCapabilities::default(), None)?;
+    client.initialize(client_capabilities, None)?;
     let fixture = fixture::parse(fixture);
     for file in fixture.files {
         client.open(file.name, file.lang, file.text)?;
@@ -58,6 +66,22 @@ fn complete(fixture: &str) -> Result<Vec<CompletionItem>, anyhow::Error> {
     Ok(actual_items)
 }
 
+fn snippet_capabilities() -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: Some(TextDocumentClientCapabilities {
+            completion: Some(CompletionClientCapabilities {
+                completion_item: Some(CompletionItemCapability {
+                    snippet_support: Some(true),
+                    ..CompletionItemCapability::default()
+                }),
+                ..CompletionClientCapabilities::default()
+            }),
+            ..TextDocumentClientCapabilities::default()
+        }),
+        ..ClientCapabilities::default()
+    }
+}
+
 macro_rules! assert_items {
     ($items:expr) => {
         assert_json_snapshot!($items, {
@@ -248,6 +272,48 @@ fn citation() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn citation_search_fields() -> Result<()> {
+    let client_capabilities = ClientCapabilities {
+        text_document: Some(TextDocumentClientCapabilities {
+            completion: Some(CompletionClientCapabilities {
+                completion_item: Some(CompletionItemCapability {
+                    label_details_support: Some(true),
+                    ..CompletionItemCapability::default()
+                }),
+                ..CompletionClientCapabilities::default()
+            }),
+            ..TextDocumentClientCapabilities::default()
+        }),
+        ..ClientCapabilities::default()
+    };
+
+    assert_items!(complete_with_capabilities(
+        r#"
+%TEX main.tex
+%SRC \addbibresource{main.bib}
+%SRC \cite{2019 nguyen transf}
+%CUR                        ^
+%1.1       ^^^^^^^^^^^^^^^^^^
+
+%BIB main.bib
+%SRC @article{smith2020a,
+%SRC     author = {Nguyễn, Van An and Smith, John and Doe, Jane},
+%SRC     title = {Attention in Transformers},
+%SRC     journal = {Journal of Examples},
+%SRC     year = {2019},
+%SRC }
+%SRC @article{smith2020b,
+%SRC     author = {Smith, John},
+%SRC     title = {Transformers Revisited},
+%SRC     year = {2020},
+%SRC }"#,
+        client_capabilities
+    )?);
+
+    Ok(())
+}
+
 #[test]
 fn citation_open_brace() -> Result<()> {
     assert_items!(complete(
@@ -456,6 +522,87 @@ fn component_command_bibtex() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn component_command_glyph() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC $α$
+%CUR   ^
+%1.1  ^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn component_command_glyph_escaped() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC $\α$
+%CUR    ^
+%1.1   ^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn component_command_glyph_text_mode() -> Result<()> {
+    let actual_items = complete(
+        r#"
+%TEX main.tex
+%SRC α
+%CUR  ^"#,
+    )?;
+
+    assert_eq!(actual_items, Vec::new());
+    Ok(())
+}
+
+#[test]
+fn component_command_label_details() -> Result<()> {
+    let client_capabilities = ClientCapabilities {
+        text_document: Some(TextDocumentClientCapabilities {
+            completion: Some(CompletionClientCapabilities {
+                completion_item: Some(CompletionItemCapability {
+                    label_details_support: Some(true),
+                    ..CompletionItemCapability::default()
+                }),
+                ..CompletionClientCapabilities::default()
+            }),
+            ..TextDocumentClientCapabilities::default()
+        }),
+        ..ClientCapabilities::default()
+    };
+
+    assert_items!(complete_with_capabilities(
+        r#"
+%TEX main.tex
+%SRC $\alph$
+%CUR      ^
+%1.1   ^^^^"#,
+        client_capabilities
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn component_command_math_boost() -> Result<()> {
+    let fixture = r#"
+%TEX main.tex
+%SRC \usepackage{amsmath}
+%SRC $\r$
+%CUR   ^
+%1.1   ^"#;
+
+    let actual_items = complete(fixture)?;
+    assert_eq!(actual_items[0].label, "eqref");
+    Ok(())
+}
+
 #[test]
 fn component_environment_simple() -> Result<()> {
     assert_items!(complete(
@@ -599,6 +746,55 @@ fn entry_type_complete_entry() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn environment_snippet_figure() -> Result<()> {
+    assert_items!(complete_with_capabilities(
+        r#"
+%TEX main.tex
+%SRC \begin{document}
+%SRC \figur
+%CUR       ^
+%1.1  ^^^^^
+%SRC \end{document}"#,
+        snippet_capabilities()
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn environment_snippet_arguments() -> Result<()> {
+    assert_items!(complete_with_capabilities(
+        r#"
+%TEX main.tex
+%SRC \begin{document}
+%SRC \tabl
+%CUR      ^
+%1.1  ^^^^
+%SRC \end{document}"#,
+        snippet_capabilities()
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn environment_snippet_user() -> Result<()> {
+    assert_items!(complete_with_capabilities(
+        r#"
+%TEX main.tex
+%SRC \newenvironment{foobar}[2][baz]{}{}
+%SRC \NewDocumentEnvironment{foobaz}{m O{qux}}{}{}
+%SRC \newtheorem{foobaq}{Foo}
+%SRC \fooba
+%CUR       ^
+%1.1  ^^^^^"#,
+        snippet_capabilities()
+    )?);
+
+    Ok(())
+}
+
 #[test]
 fn field_empty_entry_open() -> Result<()> {
     assert_items!(complete(
@@ -662,6 +858,73 @@ fn field_entry_two_fields_name_closed() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn field_value_month() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%BIB main.bib
+%SRC @article{foo, month = ja}
+%CUR                        ^
+%1.1                       ^^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn field_value_string() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%BIB main.bib
+%SRC @string{ieee = {IEEE}}
+%SRC @article{foo, journal = ie}
+%CUR                          ^
+%1.1                         ^^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn field_value_crossref() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%BIB main.bib
+%SRC @book{bar, title = {Bar}}
+%SRC @inbook{foo, crossref = {}}
+%CUR                          ^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn field_value_keywords() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%BIB main.bib
+%SRC @article{bar, keywords = {machine learning, optimization}}
+%SRC @article{foo, keywords = {graphs, mach}}
+%CUR                                     ^
+%1.1                                   ^^^^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn field_value_pubstate() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%BIB main.bib
+%SRC @article{foo, pubstate = {inp}}
+%CUR                              ^
+%1.1                           ^^^"#
+    )?);
+
+    Ok(())
+}
+
 #[test]
 fn import_package_open_brace() -> Result<()> {
     assert_items!(complete(
@@ -714,6 +977,84 @@ fn import_class_closed_brace() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn key_value_graphics_key() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC \includegraphics[scale=2, wi]{foo}
+%CUR                             ^
+%1.1                           ^^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn key_value_graphics_value() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC \includegraphics[clip=]{foo}
+%CUR                       ^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn key_value_package_key() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC \usepackage[colorl]{hyperref}
+%CUR                   ^
+%1.1             ^^^^^^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn key_value_package_value() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC \usepackage[backend=bib]{biblatex}
+%CUR                        ^
+%1.1                     ^^^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn key_value_class() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC \documentclass[]{article}
+%CUR                ^"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn key_value_tikz_library() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC \usetikzlibrary{positioning}
+%SRC \begin{tikzpicture}[thick, abo]
+%CUR                               ^
+%1.1                            ^^^
+%SRC \end{tikzpicture}"#
+    )?);
+
+    Ok(())
+}
+
 #[test]
 fn label() -> Result<()> {
     assert_items!(complete(
@@ -780,6 +1121,41 @@ fn label() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn label_cref() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC \begin{figure}
+%SRC \caption{Result}
+%SRC \label{fig:result}
+%SRC \end{figure}
+%SRC \cref{}
+%CUR       ^
+
+%TEX main.aux
+%SRC \newlabel{fig:result}{{3}{1}}"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn label_current_section() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC \section{Foo}
+%SRC \label{foo1}\label{foo2}\label{foo3}\label{foo4}\label{foo5}
+%SRC \section{Bar}
+%SRC \label{bar}
+%SRC \ref{}
+%CUR      ^"#
+    )?);
+
+    Ok(())
+}
+
 #[test]
 fn theorem_begin() -> Result<()> {
     assert_items!(complete(
@@ -851,6 +1227,72 @@ fn test_user_command() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn user_command_definition() -> Result<()> {
+    assert_items!(complete(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foobar}[2][baz]{#1 #2}
+%SRC \fooba
+%CUR    ^
+%1.1  ^^^^^
+"#
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn user_command_snippet() -> Result<()> {
+    let client_capabilities = ClientCapabilities {
+        text_document: Some(TextDocumentClientCapabilities {
+            completion: Some(CompletionClientCapabilities {
+                completion_item: Some(CompletionItemCapability {
+                    snippet_support: Some(true),
+                    ..CompletionItemCapability::default()
+                }),
+                ..CompletionClientCapabilities::default()
+            }),
+            ..TextDocumentClientCapabilities::default()
+        }),
+        ..ClientCapabilities::default()
+    };
+
+    assert_items!(complete_with_capabilities(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foobar}[2][baz]{#1 #2}
+%SRC \DeclareMathOperator{\foobaz}{foo}
+%SRC \NewDocumentCommand{\foobaq}{s o m O{qux}}{}
+%SRC \def\foobax#1#2{#1 #2}
+%SRC \fooba
+%CUR    ^
+%1.1  ^^^^^
+"#,
+        client_capabilities
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn user_command_math_operator() -> Result<()> {
+    assert_items!(complete_with_capabilities(
+        r#"
+%TEX main.tex
+%SRC \DeclareMathOperator*{\argmax}{arg\,max}
+%TEX sub.tex
+%SRC \input{main}
+%SRC \argma
+%CUR    ^
+%1.1  ^^^^^
+"#,
+        snippet_capabilities()
+    )?);
+
+    Ok(())
+}
+
 #[test]
 fn test_user_environment() -> Result<()> {
     assert_items!(complete(
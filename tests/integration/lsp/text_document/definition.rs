@@ -58,6 +58,54 @@ fn command_definition() -> Result<()> {
     )
 }
 
+#[test]
+fn document_command_definition() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \NewDocumentCommand{\foo}{m}{#1}
+%1.3                     ^^^^
+%1.2 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+%SRC \foo{bar}
+%CUR   ^
+%1.1 ^^^^
+"#,
+    )
+}
+
+#[test]
+fn plain_definition() -> Result<()> {
+    check(
+        r#"
+%TEX foo.sty
+%SRC \def\foo@bar#1\@nil{#1}
+%1.3     ^^^^^^^^
+%1.2 ^^^^^^^^^^^^^^^^^^^^^^^
+
+%TEX main.tex
+%SRC \usepackage{foo}
+%SRC \foo@bar
+%CUR    ^
+%1.1 ^^^^^^^^
+"#,
+    )
+}
+
+#[test]
+fn let_definition() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \let\foo=\bar
+%1.3     ^^^^
+%1.2 ^^^^^^^^^^^^^
+%SRC \foo
+%CUR   ^
+%1.1 ^^^^
+"#,
+    )
+}
+
 #[test]
 fn document() -> Result<()> {
     check(
@@ -78,6 +126,36 @@ fn document() -> Result<()> {
     )
 }
 
+#[test]
+fn environment_definition() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newtheorem{lemma}{Lemma}
+%1.3             ^^^^^
+%1.2 ^^^^^^^^^^^^^^^^^^^^^^^^^
+%SRC \begin{lemma}
+%CUR          ^
+%1.1        ^^^^^
+"#,
+    )
+}
+
+#[test]
+fn glossary_entry() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \DeclareAcronym{cpu}{short=CPU, long=Central Processing Unit}
+%1.3                 ^^^
+%1.2 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+%SRC \gls{cpu}
+%CUR      ^
+%1.1      ^^^
+"#,
+    )
+}
+
 #[test]
 fn entry() -> Result<()> {
     check(
@@ -0,0 +1,218 @@
+use std::{thread, time::Duration};
+
+use anyhow::Result;
+use lsp_types::{
+    notification::{
+        DidChangeConfiguration, DidChangeTextDocument, Notification, PublishDiagnostics,
+    },
+    ClientCapabilities, DidChangeConfigurationParams, DidChangeTextDocumentParams, NumberOrString,
+    PublishDiagnosticsParams, Range, TextDocumentContentChangeEvent, Url,
+    VersionedTextDocumentIdentifier,
+};
+use rustc_hash::FxHashMap;
+use serde_json::json;
+
+use crate::lsp::{client::Client, fixture};
+
+const DELAY: Duration = Duration::from_millis(500);
+
+fn check(fixture: &str, changes: &[(&str, &str)], codes: &[i32]) -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+    client.notify::<DidChangeConfiguration>(DidChangeConfigurationParams {
+        settings: json!({ "diagnosticsDelay": 10 }),
+    })?;
+
+    let fixture = fixture::parse(fixture);
+    for file in &fixture.files {
+        client.open(file.name, file.lang, file.text.clone())?;
+    }
+
+    thread::sleep(DELAY);
+    for (i, (name, text)) in changes.iter().enumerate() {
+        client.notify::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier::new(client.uri(name)?, i as i32 + 1),
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: text.to_string(),
+            }],
+        })?;
+
+        thread::sleep(DELAY);
+    }
+
+    // The cursor marks an empty range, which cannot be written as a fixture range.
+    let mut expected_ranges = Vec::new();
+    for file_range in fixture.ranges.values().flat_map(|map| map.values()) {
+        expected_ranges.push((client.uri(file_range.name)?, file_range.range));
+    }
+
+    if let Some(cursor) = fixture.cursor {
+        let range = Range::new(cursor.position, cursor.position);
+        expected_ranges.push((client.uri(cursor.name)?, range));
+    }
+
+    let mut expected_diagnostics: Vec<_> = expected_ranges
+        .into_iter()
+        .zip(codes)
+        .map(|((uri, range), code)| (uri, range, *code))
+        .collect();
+
+    let result = client.shutdown()?;
+
+    let mut diagnostics_by_uri = FxHashMap::default();
+    for notification in result.incoming.notifications.try_iter() {
+        if notification.method == PublishDiagnostics::METHOD {
+            let params: PublishDiagnosticsParams = serde_json::from_value(notification.params)?;
+            diagnostics_by_uri.insert(params.uri, params.diagnostics);
+        }
+    }
+
+    let mut actual_diagnostics: Vec<(Url, Range, i32)> = diagnostics_by_uri
+        .into_iter()
+        .flat_map(|(uri, diagnostics)| {
+            diagnostics
+                .into_iter()
+                .filter_map(move |diagnostic| match diagnostic.code? {
+                    NumberOrString::Number(code) => Some((uri.clone(), diagnostic.range, code)),
+                    NumberOrString::String(_) => None,
+                })
+        })
+        .collect();
+
+    let key = |(uri, range, _): &(Url, Range, i32)| (uri.to_string(), range.start, range.end);
+    actual_diagnostics.sort_by_key(key);
+    expected_diagnostics.sort_by_key(key);
+    assert_eq!(actual_diagnostics, expected_diagnostics);
+    Ok(())
+}
+
+#[test]
+fn bibtex_field_missing_eq() -> Result<()> {
+    check(
+        r#"
+%BIB main.bib
+%SRC @article{foo, author {bar}}
+%CUR                     ^
+"#,
+        &[],
+        &[7],
+    )
+}
+
+#[test]
+fn bibtex_field_missing_value() -> Result<()> {
+    check(
+        r#"
+%BIB main.bib
+%SRC @article{foo, author = }
+%CUR                       ^
+"#,
+        &[],
+        &[8],
+    )
+}
+
+#[test]
+fn glossary_undefined() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newacronym{gpu}{GPU}{Graphics Processing Unit}
+%SRC \gls{cpu}
+%1.1      ^^^
+"#,
+        &[],
+        &[9],
+    )
+}
+
+#[test]
+fn glossary_other_project() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newacronym{gpu}{GPU}{Graphics Processing Unit}
+%SRC \acrshort{cpu}
+%1.1           ^^^
+
+%TEX other.tex
+%SRC \newacronym{cpu}{CPU}{Central Processing Unit}
+"#,
+        &[],
+        &[9],
+    )
+}
+
+#[test]
+fn glossary_definition_added() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \input{preamble}
+%SRC \input{chapter}
+
+%TEX preamble.tex
+%SRC \newacronym{gpu}{GPU}{Graphics Processing Unit}
+
+%TEX chapter.tex
+%SRC \gls{cpu}
+"#,
+        &[(
+            "preamble.tex",
+            "\\newacronym{gpu}{GPU}{Graphics Processing Unit}\n\\newacronym{cpu}{CPU}{Central Processing Unit}\n",
+        )],
+        &[],
+    )
+}
+
+#[test]
+fn glossary_definition_removed() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \input{preamble}
+%SRC \input{chapter}
+
+%TEX preamble.tex
+%SRC \newacronym{gpu}{GPU}{Graphics Processing Unit}
+%SRC \newacronym{cpu}{CPU}{Central Processing Unit}
+
+%TEX chapter.tex
+%SRC \gls{cpu}
+%1.1      ^^^
+"#,
+        &[(
+            "preamble.tex",
+            "\\newacronym{gpu}{GPU}{Graphics Processing Unit}\n",
+        )],
+        &[9],
+    )
+}
+
+#[test]
+fn bibtex_missing_eq() -> Result<()> {
+    check(
+        r#"
+%BIB main.bib
+%SRC @article{foo, author}
+%CUR                     ^
+"#,
+        &[],
+        &[7],
+    )
+}
+
+#[test]
+fn bibtex_missing_value() -> Result<()> {
+    check(
+        r#"
+%BIB main.bib
+%SRC @article{foo, author = }
+%CUR                       ^
+"#,
+        &[],
+        &[8],
+    )
+}
@@ -0,0 +1,225 @@
+use anyhow::Result;
+use lsp_types::{
+    request::{ColorPresentationRequest, DocumentColor},
+    ClientCapabilities, Color, ColorPresentationParams, DocumentColorParams,
+    TextDocumentIdentifier,
+};
+
+use crate::lsp::{client::Client, fixture};
+
+fn find_colors(fixture: &str) -> Result<Vec<Color>> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+
+    let fixture = fixture::parse(fixture);
+    for file in fixture.files {
+        client.open(file.name, file.lang, file.text)?;
+    }
+
+    let mut expected_ranges: Vec<_> = fixture
+        .ranges
+        .values()
+        .flat_map(|map| map.values())
+        .map(|file_range| file_range.range)
+        .collect();
+    expected_ranges.sort_by_key(|range| range.start);
+
+    let mut infos = client.request::<DocumentColor>(DocumentColorParams {
+        text_document: TextDocumentIdentifier::new(client.uri("main.tex")?),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    })?;
+
+    client.shutdown()?;
+
+    infos.sort_by_key(|info| info.range.start);
+    let actual_ranges: Vec<_> = infos.iter().map(|info| info.range).collect();
+    assert_eq!(actual_ranges, expected_ranges);
+    Ok(infos.into_iter().map(|info| info.color).collect())
+}
+
+fn rgb(red: f32, green: f32, blue: f32) -> Color {
+    Color {
+        red,
+        green,
+        blue,
+        alpha: 1.0,
+    }
+}
+
+#[test]
+fn named_color() -> Result<()> {
+    let colors = find_colors(
+        r#"
+%TEX main.tex
+%SRC \textcolor{red}{foo}
+%1.1            ^^^
+"#,
+    )?;
+
+    assert_eq!(colors, vec![rgb(1.0, 0.0, 0.0)]);
+    Ok(())
+}
+
+#[test]
+fn color_expression() -> Result<()> {
+    let colors = find_colors(
+        r#"
+%TEX main.tex
+%SRC \color{-blue!50}
+%1.1        ^^^^^^^^
+"#,
+    )?;
+
+    assert_eq!(colors, vec![rgb(0.5, 0.5, 0.0)]);
+    Ok(())
+}
+
+#[test]
+fn user_definition() -> Result<()> {
+    let colors = find_colors(
+        r#"
+%TEX main.tex
+%SRC \definecolor{foo}{HTML}{FF0000}
+%1.1                  ^^^^^^^^^^^^^^
+%SRC \definecolor{bar}{RGB}{0, 0, 255}
+%2.1                  ^^^^^^^^^^^^^^^^
+%SRC \color{foo}
+%3.1        ^^^
+%SRC \color{bar}
+%4.1        ^^^
+"#,
+    )?;
+
+    assert_eq!(
+        colors,
+        vec![
+            rgb(1.0, 0.0, 0.0),
+            rgb(0.0, 0.0, 1.0),
+            rgb(1.0, 0.0, 0.0),
+            rgb(0.0, 0.0, 1.0),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn dvips_named_color() -> Result<()> {
+    let colors = find_colors(
+        r#"
+%TEX main.tex
+%SRC \textcolor{Brown}{foo}
+%1.1            ^^^^^
+"#,
+    )?;
+
+    assert_eq!(colors, vec![rgb(102.0 / 255.0, 19.0 / 255.0, 0.0)]);
+    Ok(())
+}
+
+#[test]
+fn svg_named_color() -> Result<()> {
+    let colors = find_colors(
+        r#"
+%TEX main.tex
+%SRC \color{Crimson}
+%1.1        ^^^^^^^
+"#,
+    )?;
+
+    assert_eq!(colors, vec![rgb(220.0 / 255.0, 20.0 / 255.0, 60.0 / 255.0)]);
+    Ok(())
+}
+
+#[test]
+fn user_definition_included() -> Result<()> {
+    let colors = find_colors(
+        r#"
+%TEX main.tex
+%SRC \input{colors}
+%SRC \definecolor{bar}{named}{foo!50}
+%1.1                  ^^^^^^^^^^^^^^^
+%SRC \color{bar}
+%2.1        ^^^
+
+%TEX colors.tex
+%SRC \definecolor{foo}{HTML}{FF0000}
+"#,
+    )?;
+
+    assert_eq!(colors, vec![rgb(1.0, 0.5, 0.5), rgb(1.0, 0.5, 0.5)]);
+    Ok(())
+}
+
+#[test]
+fn unknown_color() -> Result<()> {
+    find_colors(
+        r#"
+%TEX main.tex
+%SRC \color{foo}
+"#,
+    )?;
+
+    Ok(())
+}
+
+fn find_presentations(fixture: &str) -> Result<Vec<String>> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+
+    let fixture = fixture::parse(fixture);
+    for file in fixture.files {
+        client.open(file.name, file.lang, file.text)?;
+    }
+
+    let range = fixture.ranges[&1][&1].range;
+    let presentations = client.request::<ColorPresentationRequest>(ColorPresentationParams {
+        text_document: TextDocumentIdentifier::new(client.uri("main.tex")?),
+        color: rgb(1.0, 0.5, 0.0),
+        range,
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    })?;
+
+    client.shutdown()?;
+
+    Ok(presentations
+        .into_iter()
+        .map(|presentation| {
+            let edit = presentation.text_edit.unwrap();
+            assert_eq!(edit.range, range);
+            edit.new_text
+        })
+        .collect())
+}
+
+#[test]
+fn presentation_definition() -> Result<()> {
+    let presentations = find_presentations(
+        r#"
+%TEX main.tex
+%SRC \definecolor{foo}{rgb}{1, 0, 0}
+%1.1                  ^^^^^^^^^^^^^^
+"#,
+    )?;
+
+    assert_eq!(
+        presentations,
+        vec!["{HTML}{FF8000}", "{rgb}{1, 0.5, 0}", "{RGB}{255, 128, 0}"]
+    );
+    Ok(())
+}
+
+#[test]
+fn presentation_reference() -> Result<()> {
+    let presentations = find_presentations(
+        r#"
+%TEX main.tex
+%SRC \color{red}
+%1.1        ^^^
+"#,
+    )?;
+
+    assert!(presentations.is_empty());
+    Ok(())
+}
@@ -46,6 +46,37 @@ fn check(fixture: &str) -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_command() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foo}{x}
+%1.3             ^^^^
+%SRC \foo
+%CUR   ^
+%2.2 ^^^^
+%SRC \bar
+"#,
+    )
+}
+
+#[test]
+fn test_environment() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newtheorem{lemma}{Lemma}
+%1.3             ^^^^^
+%SRC \begin{lemma}
+%CUR          ^
+%2.2        ^^^^^
+%SRC \end{lemma}
+%3.2      ^^^^^
+"#,
+    )
+}
+
 #[test]
 fn test_label() -> Result<()> {
     check(
@@ -2,25 +2,41 @@ use anyhow::Result;
 use assert_unordered::assert_eq_unordered;
 use lsp_types::{
     request::DocumentLinkRequest, ClientCapabilities, DocumentLink, DocumentLinkParams,
-    TextDocumentIdentifier,
+    TextDocumentIdentifier, Url,
 };
 
 use crate::lsp::{client::Client, fixture};
 
 fn check(fixture: &str) -> Result<()> {
+    check_with(fixture, &[], &[])
+}
+
+fn check_with(fixture: &str, files_on_disk: &[&str], urls: &[&str]) -> Result<()> {
     let mut client = Client::spawn()?;
     client.initialize(ClientCapabilities::default(), None)?;
 
+    for name in files_on_disk {
+        let path = client.uri(name)?.to_file_path().unwrap();
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(path, "")?;
+    }
+
     let fixture = fixture::parse(fixture);
     for file in fixture.files {
         client.open(file.name, file.lang, file.text)?;
     }
 
+    let mut urls = urls.iter();
     let mut expected_links = Vec::new();
     for ranges in fixture.ranges.values() {
+        let target = match ranges.get(&2) {
+            Some(range) => client.uri(range.name)?,
+            None => Url::parse(urls.next().unwrap())?,
+        };
+
         expected_links.push(DocumentLink {
             range: ranges[&1].range,
-            target: Some(client.uri(ranges[&2].name)?),
+            target: Some(target),
             tooltip: None,
             data: None,
         });
@@ -71,3 +87,79 @@ fn document_import() -> Result<()> {
 "#,
     )
 }
+
+#[test]
+fn graphics() -> Result<()> {
+    check_with(
+        r#"
+%TEX foo.tex
+%SRC \graphicspath{{plots/}}
+%SRC \includegraphics{fig1}
+%1.1                  ^^^^
+%SRC \includegraphics{fig2}
+%CUR ^
+
+%TEX plots/fig1.png
+%SRC 
+%1.2 
+"#,
+        &["plots/fig1.png"],
+        &[],
+    )
+}
+
+#[test]
+fn url() -> Result<()> {
+    check_with(
+        r#"
+%TEX foo.tex
+%SRC \url{https://example.com/foo}
+%1.1      ^^^^^^^^^^^^^^^^^^^^^^^
+%SRC \href{https://example.com/bar}{Bar}
+%2.1       ^^^^^^^^^^^^^^^^^^^^^^^
+%CUR ^
+"#,
+        &[],
+        &["https://example.com/foo", "https://example.com/bar"],
+    )
+}
+
+#[test]
+fn bibtex_fields() -> Result<()> {
+    check_with(
+        r#"
+%BIB main.bib
+%SRC @article{foo,
+%CUR ^
+%SRC     doi = {10.1000/182},
+%1.1            ^^^^^^^^^^^
+%SRC     eprint = {2107.11903},
+%2.1               ^^^^^^^^^^
+%SRC     url = "https://example.com",
+%3.1            ^^^^^^^^^^^^^^^^^^^
+%SRC }
+"#,
+        &[],
+        &[
+            "https://doi.org/10.1000/182",
+            "https://arxiv.org/abs/2107.11903",
+            "https://example.com",
+        ],
+    )
+}
+
+#[test]
+fn bibtex_url_tilde() -> Result<()> {
+    check_with(
+        r#"
+%BIB main.bib
+%SRC @misc{foo,
+%CUR ^
+%SRC     url = {https://example.com/~user/paper.pdf},
+%1.1            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+%SRC }
+"#,
+        &[],
+        &["https://example.com/~user/paper.pdf"],
+    )
+}
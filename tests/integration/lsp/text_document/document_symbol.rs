@@ -1,7 +1,8 @@
 use anyhow::Result;
 use insta::{assert_json_snapshot, internals::Redaction};
 use lsp_types::{
-    request::DocumentSymbolRequest, DocumentSymbolParams, DocumentSymbolResponse,
+    notification::DidChangeConfiguration, request::DocumentSymbolRequest,
+    DidChangeConfigurationParams, DocumentSymbolParams, DocumentSymbolResponse,
     TextDocumentIdentifier, Url,
 };
 
@@ -13,8 +14,17 @@ struct SymbolResult {
 }
 
 fn find_symbols(fixture: &str, client_capabilities: serde_json::Value) -> Result<SymbolResult> {
+    find_symbols_with_settings(fixture, client_capabilities, serde_json::json!({}))
+}
+
+fn find_symbols_with_settings(
+    fixture: &str,
+    client_capabilities: serde_json::Value,
+    settings: serde_json::Value,
+) -> Result<SymbolResult> {
     let mut client = Client::spawn()?;
     client.initialize(serde_json::from_value(client_capabilities)?, None)?;
+    client.notify::<DidChangeConfiguration>(DidChangeConfigurationParams { settings })?;
 
     let fixture = fixture::parse(fixture);
     let file = fixture.files.into_iter().next().unwrap();
@@ -340,6 +350,36 @@ fn section_flat() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn section_custom_command() -> Result<()> {
+    assert_symbols!(find_symbols_with_settings(
+        r#"
+%TEX main.tex
+%SRC \documentclass{book}
+%SRC 
+%SRC \begin{document}
+%SRC 
+%SRC \mychapter{Foo}
+%SRC 
+%SRC \section{Bar}
+%SRC 
+%SRC \mychapter{Baz}
+%SRC 
+%SRC \end{document}
+"#,
+        serde_json::json!({
+            "textDocument": {
+                "documentSymbol": {
+                    "hierarchicalDocumentSymbolSupport": true,
+                },
+            },
+        }),
+        serde_json::json!({ "experimental": { "sectionCommands": { "mychapter": "chapter" } } }),
+    )?);
+
+    Ok(())
+}
+
 #[test]
 fn theorem_nested() -> Result<()> {
     assert_symbols!(find_symbols(
@@ -425,3 +465,80 @@ fn theorem_flat() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn beamer_nested() -> Result<()> {
+    assert_symbols!(find_symbols(
+        r#"
+%TEX main.tex
+%SRC \documentclass{beamer}
+%SRC 
+%SRC \begin{document}
+%SRC 
+%SRC \section{Foo}
+%SRC 
+%SRC \begin{frame}
+%SRC     \frametitle{Bar}
+%SRC     \begin{block}{Baz}
+%SRC         Baz
+%SRC     \end{block}
+%SRC \end{frame}
+%SRC 
+%SRC \begin{frame}[label=qux]{Qux}
+%SRC     \begin{columns}
+%SRC         \begin{alertblock}{Quux}
+%SRC             Quux
+%SRC         \end{alertblock}
+%SRC     \end{columns}
+%SRC \end{frame}
+%SRC 
+%SRC \begin{frame}
+%SRC     Corge
+%SRC \end{frame}
+%SRC 
+%SRC \againframe{qux}
+%SRC 
+%SRC \end{document}
+"#,
+        serde_json::json!({
+            "textDocument": {
+                "documentSymbol": {
+                    "hierarchicalDocumentSymbolSupport": true,
+                },
+            },
+        }),
+    )?);
+
+    Ok(())
+}
+
+#[test]
+fn beamer_empty_title() -> Result<()> {
+    assert_symbols!(find_symbols(
+        r#"
+%TEX main.tex
+%SRC \documentclass{beamer}
+%SRC 
+%SRC \begin{document}
+%SRC 
+%SRC \begin{frame}{}
+%SRC     \frametitle{Foo}
+%SRC \end{frame}
+%SRC 
+%SRC \begin{frame}{}
+%SRC     Bar
+%SRC \end{frame}
+%SRC 
+%SRC \end{document}
+"#,
+        serde_json::json!({
+            "textDocument": {
+                "documentSymbol": {
+                    "hierarchicalDocumentSymbolSupport": true,
+                },
+            },
+        }),
+    )?);
+
+    Ok(())
+}
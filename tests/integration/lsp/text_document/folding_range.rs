@@ -8,6 +8,19 @@ use lsp_types::{
 use crate::lsp::{client::Client, fixture};
 
 fn check(fixture: &str, expected_ranges: Vec<(u32, u32, u32, u32)>) -> Result<()> {
+    check_kinds(
+        fixture,
+        expected_ranges
+            .into_iter()
+            .map(|(a, b, c, d)| (a, b, c, d, FoldingRangeKind::Region))
+            .collect(),
+    )
+}
+
+fn check_kinds(
+    fixture: &str,
+    expected_ranges: Vec<(u32, u32, u32, u32, FoldingRangeKind)>,
+) -> Result<()> {
     let mut client = Client::spawn()?;
     client.initialize(ClientCapabilities::default(), None)?;
 
@@ -29,12 +42,12 @@ fn check(fixture: &str, expected_ranges: Vec<(u32, u32, u32, u32)>) -> Result<()
     let expected_foldings = expected_ranges
         .into_iter()
         .map(
-            |(start_line, start_character, end_line, end_character)| FoldingRange {
+            |(start_line, start_character, end_line, end_character, kind)| FoldingRange {
                 start_line,
                 start_character: Some(start_character),
                 end_line,
                 end_character: Some(end_character),
-                kind: Some(FoldingRangeKind::Region),
+                kind: Some(kind),
             },
         )
         .collect();
@@ -69,9 +82,29 @@ fn latex() -> Result<()> {
     )
 }
 
+#[test]
+fn bibtex_comments() -> Result<()> {
+    check_kinds(
+        r#"
+%BIB main.bib
+%SRC @comment{foo
+%SRC bar}
+%SRC % region Foo
+%SRC @article{foo,}
+%SRC % endregion
+%CUR ^
+"#,
+        vec![
+            (0, 0, 1, 4, FoldingRangeKind::Comment),
+            (2, 0, 4, 11, FoldingRangeKind::Region),
+            (3, 0, 3, 14, FoldingRangeKind::Region),
+        ],
+    )
+}
+
 #[test]
 fn bibtex() -> Result<()> {
-    check(
+    check_kinds(
         r#"
 %BIB main.bib
 %SRC some junk
@@ -92,6 +125,119 @@ fn bibtex() -> Result<()> {
 %SRC @preamble{"foo"}
 %CUR ^
 "#,
-        vec![(3, 0, 6, 1), (8, 0, 8, 20), (15, 0, 15, 16)],
+        vec![
+            (3, 0, 6, 1, FoldingRangeKind::Region),
+            (8, 0, 8, 20, FoldingRangeKind::Region),
+            (10, 0, 13, 1, FoldingRangeKind::Comment),
+            (15, 0, 15, 16, FoldingRangeKind::Region),
+        ],
+    )
+}
+
+#[test]
+fn latex_preamble() -> Result<()> {
+    check_kinds(
+        r#"
+%TEX main.tex
+%SRC \documentclass{article}
+%SRC \usepackage{amsmath}
+%SRC \begin{document}
+%SRC \end{document}
+%CUR ^
+"#,
+        vec![
+            (0, 0, 1, 20, FoldingRangeKind::Imports),
+            (2, 0, 3, 14, FoldingRangeKind::Region),
+        ],
+    )
+}
+
+#[test]
+fn latex_comments() -> Result<()> {
+    check_kinds(
+        r#"
+%TEX main.tex
+%SRC % foo
+%SRC % bar
+%SRC text % baz
+%SRC % qux
+%SRC % region Foo
+%SRC \foo
+%SRC % endregion
+%CUR ^
+"#,
+        vec![
+            (0, 0, 1, 5, FoldingRangeKind::Comment),
+            (4, 0, 6, 11, FoldingRangeKind::Region),
+        ],
+    )
+}
+
+#[test]
+fn latex_conditionals() -> Result<()> {
+    check_kinds(
+        r#"
+%TEX main.tex
+%SRC \newif\ifdraft
+%SRC \iffalse
+%SRC foo
+%SRC \fi
+%SRC \ifdraft
+%SRC bar
+%SRC \fi
+%SRC \begin{comment}
+%SRC baz
+%SRC \end{comment}
+%CUR ^
+"#,
+        vec![
+            (1, 0, 3, 3, FoldingRangeKind::Comment),
+            (4, 0, 6, 3, FoldingRangeKind::Region),
+            (7, 0, 9, 13, FoldingRangeKind::Comment),
+        ],
+    )
+}
+
+#[test]
+fn latex_conditionals_non_primitive() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \ifnum\value{page}>1
+%SRC \ifthenelse{\boolean{draft}}{foo}{bar}
+%SRC $a \iff b$
+%SRC \fi
+%CUR ^
+"#,
+        vec![(0, 0, 3, 3)],
+    )
+}
+
+#[test]
+fn latex_conditionals_unknown_macro() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \iflanguage{english}{foo}{bar}
+%SRC \ifx\foo\bar
+%SRC \iflanguage{ngerman}{baz}{qux}
+%SRC \fi
+%CUR ^
+"#,
+        vec![(1, 0, 3, 3)],
+    )
+}
+
+#[test]
+fn latex_arguments() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \caption{Foo
+%SRC     bar}
+%SRC \caption{Baz}
+%CUR ^
+"#,
+        vec![(0, 8, 1, 8)],
     )
 }
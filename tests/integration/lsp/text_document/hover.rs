@@ -201,6 +201,56 @@ fn field_unknown() -> Result<()> {
     )
 }
 
+#[test]
+fn glossary_entry() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newglossaryentry{api}{name={API}, description={A set of functions}}
+%SRC \gls{api}
+%CUR      ^
+%1.1      ^^^
+"#,
+        Some(HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::PlainText,
+            value: "API\n\nA set of functions".to_string(),
+        })),
+    )
+}
+
+#[test]
+fn acronym() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newacronym{cpu}{CPU}{Central Processing Unit}
+%SRC \acrshort{cpu}
+%CUR           ^
+%1.1           ^^^
+"#,
+        Some(HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::PlainText,
+            value: "CPU (Central Processing Unit)".to_string(),
+        })),
+    )
+}
+
+#[test]
+fn acronym_other_project() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newacronym{gpu}{GPU}{Graphics Processing Unit}
+%SRC \acrshort{cpu}
+%CUR           ^
+
+%TEX other.tex
+%SRC \newacronym{cpu}{CPU}{Central Processing Unit}
+"#,
+        None,
+    )
+}
+
 #[test]
 fn section() -> Result<()> {
     check(
@@ -218,6 +268,44 @@ fn section() -> Result<()> {
     )
 }
 
+#[test]
+fn user_command() -> Result<()> {
+    check(
+        r#"
+%TEX foo.sty
+%SRC \gdef\foo#1{#1}
+
+%TEX main.tex
+%SRC \usepackage{foo}
+%SRC \foo{bar}
+%CUR  ^
+%1.1 ^^^^
+"#,
+        Some(HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: "```latex\n\\gdef\\foo#1{#1}\n```".into(),
+        })),
+    )
+}
+
+#[test]
+fn user_command_long_body() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foo}[1]{\textbf{#1}
+%SRC   \textit{#1}}
+%SRC \foo{bar}
+%CUR  ^
+%1.1 ^^^^
+"#,
+        Some(HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: "```latex\n\\newcommand{\\foo}[1]{\\textbf{#1} ...\n```".into(),
+        })),
+    )
+}
+
 #[test]
 fn string_inside_reference() -> Result<()> {
     check(
@@ -0,0 +1,128 @@
+use anyhow::Result;
+use lsp_types::{
+    notification::DidChangeConfiguration, request::InlayHintRequest, ClientCapabilities,
+    DidChangeConfigurationParams, InlayHintLabel, InlayHintParams, Position, Range,
+    TextDocumentIdentifier,
+};
+
+use crate::lsp::{client::Client, fixture};
+
+fn check(
+    fixture: &str,
+    settings: serde_json::Value,
+    expected: Vec<(Position, &str)>,
+) -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+    client.notify::<DidChangeConfiguration>(DidChangeConfigurationParams { settings })?;
+
+    let fixture = fixture::parse(fixture);
+    let main_uri = client.uri(fixture.files[0].name)?;
+    let main_text = &fixture.files[0].text;
+    let end = Position::new(
+        main_text.lines().count() as u32 - 1,
+        main_text.lines().last().unwrap().len() as u32,
+    );
+
+    for file in fixture.files {
+        client.open(file.name, file.lang, file.text)?;
+    }
+
+    let actual_hints = client
+        .request::<InlayHintRequest>(InlayHintParams {
+            text_document: TextDocumentIdentifier::new(main_uri),
+            range: Range::new(Position::new(0, 0), end),
+            work_done_progress_params: Default::default(),
+        })?
+        .unwrap_or_default();
+
+    client.shutdown()?;
+
+    let actual: Vec<_> = actual_hints
+        .into_iter()
+        .map(|hint| match hint.label {
+            InlayHintLabel::String(label) => (hint.position, label),
+            InlayHintLabel::LabelParts(_) => unreachable!(),
+        })
+        .collect();
+
+    let expected: Vec<_> = expected
+        .into_iter()
+        .map(|(position, label)| (position, label.to_string()))
+        .collect();
+
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn label_references() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \begin{figure}
+%SRC     \caption{Foo}
+%SRC     \label{fig:foo}
+%SRC \end{figure}
+%SRC \begin{equation}\label{eq:bar}
+%SRC     1 + 1 = 2
+%SRC \end{equation}
+%SRC \ref{fig:foo} \eqref{eq:bar} \ref{baz}
+
+%TEX main.aux
+%SRC \newlabel{fig:foo}{{3}{1}}
+%SRC \newlabel{eq:bar}{{2.4}{1}}
+"#,
+        serde_json::json!({}),
+        vec![
+            (Position::new(7, 13), "Figure 3"),
+            (Position::new(7, 28), "(2.4)"),
+        ],
+    )
+}
+
+#[test]
+fn label_without_number() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \section{Foo}\label{sec:foo}
+%SRC \ref{sec:foo}
+"#,
+        serde_json::json!({}),
+        Vec::new(),
+    )
+}
+
+#[test]
+fn citations_disabled() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \addbibresource{main.bib}
+%SRC \cite{foo}
+
+%BIB main.bib
+%SRC @book{foo, author = {Knuth, Donald E.}, year = {1984}}
+"#,
+        serde_json::json!({}),
+        Vec::new(),
+    )
+}
+
+#[test]
+fn citations_enabled() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \addbibresource{main.bib}
+%SRC \cite{foo,bar}
+
+%BIB main.bib
+%SRC @book{foo, author = {Knuth, Donald E.}, year = {1984}}
+%SRC @book{bar, author = {Lamport, Leslie}, year = {1994}}
+"#,
+        serde_json::json!({ "inlayHints": { "citations": true } }),
+        vec![(Position::new(1, 14), "Knuth 1984; Lamport 1994")],
+    )
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use lsp_types::{request::LinkedEditingRange, ClientCapabilities, LinkedEditingRangeParams};
+
+use crate::lsp::{client::Client, fixture};
+
+fn check(fixture: &str) -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+
+    let fixture = fixture::parse(fixture);
+    for file in fixture.files {
+        client.open(file.name, file.lang, file.text)?;
+    }
+
+    let mut expected_ranges: Vec<_> = fixture
+        .ranges
+        .values()
+        .flat_map(|map| map.values())
+        .map(|file_range| file_range.range)
+        .collect();
+    expected_ranges.sort_by_key(|range| range.start);
+
+    let actual_ranges = client
+        .request::<LinkedEditingRange>(LinkedEditingRangeParams {
+            text_document_position_params: fixture.cursor.unwrap().into_params(&client)?,
+            work_done_progress_params: Default::default(),
+        })?
+        .map(|result| result.ranges)
+        .unwrap_or_default();
+
+    client.shutdown()?;
+
+    assert_eq!(actual_ranges, expected_ranges);
+    Ok(())
+}
+
+#[test]
+fn begin() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \begin{foo}
+%CUR         ^
+%1.1        ^^^
+%SRC \end{foo}
+%2.1      ^^^
+"#,
+    )
+}
+
+#[test]
+fn end_starred() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \begin{align*}
+%1.1        ^^^^^^
+%SRC \end{align*}
+%CUR        ^
+%2.1      ^^^^^^
+"#,
+    )
+}
+
+#[test]
+fn nested() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \begin{foo}
+%SRC \begin{bar}
+%CUR         ^
+%1.1        ^^^
+%SRC \end{bar}
+%2.1      ^^^
+%SRC \end{foo}
+"#,
+    )
+}
+
+#[test]
+fn outside_name() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \begin{foo}
+%SRC     bar
+%CUR      ^
+%SRC \end{foo}
+"#,
+    )
+}
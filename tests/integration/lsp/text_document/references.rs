@@ -1,14 +1,25 @@
 use anyhow::Result;
 use assert_unordered::assert_eq_unordered;
 use lsp_types::{
-    request::References, ClientCapabilities, Location, ReferenceContext, ReferenceParams,
+    notification::DidChangeConfiguration, request::References, ClientCapabilities,
+    DidChangeConfigurationParams, Location, ReferenceContext, ReferenceParams,
 };
+use serde_json::json;
 
 use crate::lsp::{client::Client, fixture};
 
 fn check(fixture: &str, context: ReferenceContext) -> Result<()> {
+    check_with_settings(fixture, context, json!({}))
+}
+
+fn check_with_settings(
+    fixture: &str,
+    context: ReferenceContext,
+    settings: serde_json::Value,
+) -> Result<()> {
     let mut client = Client::spawn()?;
     client.initialize(ClientCapabilities::default(), None)?;
+    client.notify::<DidChangeConfiguration>(DidChangeConfigurationParams { settings })?;
 
     let fixture = fixture::parse(fixture);
     for file in fixture.files {
@@ -38,6 +49,45 @@ fn check(fixture: &str, context: ReferenceContext) -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn command_definition() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\todo}[1]{#1}
+%CUR               ^
+%SRC \todo{foo}
+%1.1 ^^^^^
+%SRC \include{bar}
+
+%TEX bar.tex
+%SRC \todo{bar}
+%2.1 ^^^^^
+%SRC \textbf{baz}
+"#,
+        ReferenceContext {
+            include_declaration: false,
+        },
+    )
+}
+
+#[test]
+fn command_reference_include_decl() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\todo}[1]{#1}
+%2.1             ^^^^^
+%SRC \todo{foo}
+%CUR   ^
+%1.1 ^^^^^
+"#,
+        ReferenceContext {
+            include_declaration: true,
+        },
+    )
+}
+
 #[test]
 fn entry_definition() -> Result<()> {
     check(
@@ -116,6 +166,45 @@ fn entry_reference_include_decl() -> Result<()> {
     )
 }
 
+#[test]
+fn environment_definition() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newenvironment{proof}{}{}
+%CUR                   ^
+%3.1                 ^^^^^
+%SRC \begin{proof}
+%1.1        ^^^^^
+%SRC \end{proof}
+%2.1      ^^^^^
+"#,
+        ReferenceContext {
+            include_declaration: true,
+        },
+    )
+}
+
+#[test]
+fn environment_reference() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newtheorem{lemma}{Lemma}
+%SRC \begin{lemma}
+%CUR          ^
+%1.1        ^^^^^
+%SRC \end{lemma}
+%2.1      ^^^^^
+%SRC \begin{proof}
+%SRC \end{proof}
+"#,
+        ReferenceContext {
+            include_declaration: false,
+        },
+    )
+}
+
 #[test]
 fn label_definition() -> Result<()> {
     check(
@@ -135,6 +224,24 @@ fn label_definition() -> Result<()> {
     )
 }
 
+#[test]
+fn label_definition_custom_command() -> Result<()> {
+    check_with_settings(
+        r#"
+%TEX foo.tex
+%SRC \label{foo}
+%CUR         ^
+%2.1        ^^^
+%SRC \figref{foo}
+%1.1         ^^^
+"#,
+        ReferenceContext {
+            include_declaration: true,
+        },
+        json!({ "experimental": { "labelReferenceCommands": ["figref"] } }),
+    )
+}
+
 #[test]
 fn label_definition_include_decl() -> Result<()> {
     check(
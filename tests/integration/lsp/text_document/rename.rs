@@ -109,3 +109,63 @@ fn label() -> Result<()> {
         "bar",
     )
 }
+
+#[test]
+fn environment() -> Result<()> {
+    check(
+        r#"
+%TEX foo.tex
+%SRC \newenvironment{foo}{}{}
+%1.1                 ^^^
+%SRC \begin{foo}
+%CUR         ^
+%2.1        ^^^
+%SRC \end{foo}
+%3.1      ^^^
+%SRC \include{bar.tex}
+
+%TEX bar.tex
+%SRC \begin{foo}\end{foo}
+%4.1        ^^^
+%5.1                 ^^^
+"#,
+        "qux",
+    )
+}
+
+#[test]
+fn glossary_entry() -> Result<()> {
+    check(
+        r#"
+%TEX foo.tex
+%SRC \newglossaryentry{foo}{name={Foo}}
+%1.1                   ^^^
+%SRC \gls{foo}
+%CUR       ^
+%2.1      ^^^
+%SRC \include{bar.tex}
+
+%TEX bar.tex
+%SRC \Gls{foo}
+%3.1      ^^^
+"#,
+        "qux",
+    )
+}
+
+#[test]
+fn acronym() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newacronym{foo}{FOO}{Foo Bar}
+%CUR             ^
+%1.1             ^^^
+%SRC \acrshort{foo}
+%2.1           ^^^
+%SRC \gls{foo}
+%3.1      ^^^
+"#,
+        "qux",
+    )
+}
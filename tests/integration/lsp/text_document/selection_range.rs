@@ -0,0 +1,85 @@
+use anyhow::Result;
+use lsp_types::{
+    request::SelectionRangeRequest, ClientCapabilities, Position, Range, SelectionRangeParams,
+};
+
+use crate::lsp::{client::Client, fixture};
+
+fn check(fixture: &str, expected_ranges: Vec<[(u32, u32); 2]>) -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+
+    let fixture = fixture::parse(fixture);
+    for file in fixture.files {
+        client.open(file.name, file.lang, file.text)?;
+    }
+
+    let cursor = fixture.cursor.unwrap().into_params(&client)?;
+    let actual_selection = client
+        .request::<SelectionRangeRequest>(SelectionRangeParams {
+            text_document: cursor.text_document,
+            positions: vec![cursor.position],
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })?
+        .unwrap_or_default();
+
+    client.shutdown()?;
+
+    let mut actual_ranges = Vec::new();
+    let mut selection = actual_selection.into_iter().next();
+    while let Some(current) = selection {
+        actual_ranges.push(current.range);
+        selection = current.parent.map(|parent| *parent);
+    }
+
+    let expected_ranges: Vec<_> = expected_ranges
+        .into_iter()
+        .map(|[(l1, c1), (l2, c2)]| Range::new(Position::new(l1, c1), Position::new(l2, c2)))
+        .collect();
+
+    assert_eq!(actual_ranges, expected_ranges);
+    Ok(())
+}
+
+#[test]
+fn latex_environment() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \section{Foo}
+%SRC \begin{center}
+%SRC     \textbf{bar baz}
+%CUR              ^
+%SRC \end{center}
+"#,
+        vec![
+            [(2, 12), (2, 15)],
+            [(2, 12), (2, 19)],
+            [(2, 11), (2, 20)],
+            [(2, 4), (2, 20)],
+            [(1, 0), (3, 12)],
+            [(0, 0), (3, 12)],
+        ],
+    )
+}
+
+#[test]
+fn bibtex_field() -> Result<()> {
+    check(
+        r#"
+%BIB main.bib
+%SRC @article{foo,
+%SRC     title = {Bar},
+%CUR               ^
+%SRC }
+"#,
+        vec![
+            [(1, 13), (1, 16)],
+            [(1, 12), (1, 17)],
+            [(1, 4), (1, 18)],
+            [(0, 0), (2, 1)],
+            [(0, 0), (3, 0)],
+        ],
+    )
+}
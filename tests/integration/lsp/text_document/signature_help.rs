@@ -0,0 +1,215 @@
+use anyhow::Result;
+use lsp_types::{request::SignatureHelpRequest, ClientCapabilities, SignatureHelpParams};
+
+use crate::lsp::{client::Client, fixture};
+
+fn check(fixture: &str, expected: Option<(&str, u32)>) -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+
+    let fixture = fixture::parse(fixture);
+    for file in fixture.files {
+        client.open(file.name, file.lang, file.text)?;
+    }
+
+    let actual_help = client.request::<SignatureHelpRequest>(SignatureHelpParams {
+        context: None,
+        text_document_position_params: fixture.cursor.unwrap().into_params(&client)?,
+        work_done_progress_params: Default::default(),
+    })?;
+
+    client.shutdown()?;
+
+    let actual = actual_help.map(|help| {
+        let signature = &help.signatures[0];
+        (signature.label.clone(), help.active_parameter.unwrap())
+    });
+
+    let expected = expected.map(|(label, index)| (label.to_string(), index));
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn user_command_mandatory() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foo}[2][bar]{#1 #2}
+%SRC \foo{baz}
+%CUR      ^
+"#,
+        Some((r#"\foo[bar]{#2}"#, 1)),
+    )
+}
+
+#[test]
+fn user_command_optional() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foo}[2][bar]{#1 #2}
+%SRC \foo[qux]{baz}
+%CUR      ^
+"#,
+        Some((r#"\foo[bar]{#2}"#, 0)),
+    )
+}
+
+#[test]
+fn document_command() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \NewDocumentCommand{\foo}{s O{bar} m}{#2 #3}
+%SRC \foo*[qux]{baz}
+%CUR            ^
+"#,
+        Some((r#"\foo[bar]{#3}"#, 1)),
+    )
+}
+
+#[test]
+fn document_command_brace_default() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \NewDocumentCommand{\foo}{O{abc} G{xyz} m}{#1 #3}
+%SRC \foo[qux]{baz}
+%CUR            ^
+"#,
+        Some((r#"\foo[abc]{#3}"#, 1)),
+    )
+}
+
+#[test]
+fn document_command_until() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \NewDocumentCommand{\foo}{m u{stop} m}{#1 #3}
+%SRC \foo{bar}
+%CUR      ^
+"#,
+        Some((r#"\foo{#1}{#3}"#, 0)),
+    )
+}
+
+#[test]
+fn document_environment() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \NewDocumentEnvironment{foo}{m o}{}{}
+%SRC \begin{foo}{bar}[baz]
+%CUR                  ^
+%SRC \end{foo}
+"#,
+        Some((r#"\begin{foo}{#1}[#2]"#, 1)),
+    )
+}
+
+#[test]
+fn component_environment() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \begin{table}[ht]
+%CUR               ^
+%SRC \end{table}
+"#,
+        Some((r#"\begin{table}[placement]"#, 0)),
+    )
+}
+
+#[test]
+fn user_command_second_argument() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foo}[3]{#1 #2 #3}
+%SRC \foo{a}{b}{c}
+%CUR         ^
+"#,
+        Some((r#"\foo{#1}{#2}{#3}"#, 1)),
+    )
+}
+
+#[test]
+fn user_command_nested() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foo}[1]{#1}
+%SRC \foo{\textbf{baz}}
+%CUR               ^
+"#,
+        Some((r#"\foo{#1}"#, 0)),
+    )
+}
+
+#[test]
+fn user_command_unclosed() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foo}[1]{#1}
+%SRC \foo{
+%CUR      ^
+"#,
+        Some((r#"\foo{#1}"#, 0)),
+    )
+}
+
+#[test]
+fn user_command_outside() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newcommand{\foo}[1]{#1}
+%SRC \foo{baz}
+%CUR          ^
+"#,
+        None,
+    )
+}
+
+#[test]
+fn user_environment() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newenvironment{foo}[2][bar]{}{}
+%SRC \begin{foo}[baz]{qux}
+%CUR                  ^
+%SRC \end{foo}
+"#,
+        Some((r#"\begin{foo}[bar]{#2}"#, 1)),
+    )
+}
+
+#[test]
+fn environment_name() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \newenvironment{foo}[1]{}{}
+%SRC \begin{foo}{qux}
+%CUR         ^
+%SRC \end{foo}
+"#,
+        None,
+    )
+}
+
+#[test]
+fn unknown_command() -> Result<()> {
+    check(
+        r#"
+%TEX main.tex
+%SRC \foo{bar}
+%CUR      ^
+"#,
+        None,
+    )
+}
@@ -1 +1,2 @@
+mod project_tree;
 mod symbol;
@@ -0,0 +1,68 @@
+use anyhow::Result;
+use insta::assert_json_snapshot;
+use lsp_types::{ClientCapabilities, TextDocumentIdentifier, Url};
+use texlab::features::{ProjectTreeNode, ProjectTreeParams};
+
+use crate::lsp::{client::Client, fixture};
+
+struct ProjectTreeRequest;
+
+impl lsp_types::request::Request for ProjectTreeRequest {
+    type Params = ProjectTreeParams;
+
+    type Result = Option<ProjectTreeNode>;
+
+    const METHOD: &'static str = "texlab/projectTree";
+}
+
+#[test]
+fn include_tree() -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+
+    let fixture = fixture::parse(
+        r#"
+%TEX main.tex
+%SRC \documentclass{article}
+%SRC \usepackage{graphicx}
+%SRC \begin{document}
+%SRC \include{chapters/foo}
+%SRC \input{bar.tex}
+%SRC \includegraphics{baz}
+%SRC \bibliography{main}
+%SRC \end{document}
+
+%TEX chapters/foo.tex
+%SRC \subfile{qux}
+
+%BIB main.bib
+%SRC @article{foo,}
+"#,
+    );
+
+    for file in fixture.files {
+        client.open(file.name, file.lang, file.text)?;
+    }
+
+    let tree = client.request::<ProjectTreeRequest>(ProjectTreeParams {
+        text_document: TextDocumentIdentifier::new(client.uri("chapters/foo.tex")?),
+    })?;
+
+    let result = client.shutdown()?;
+
+    let uri = Url::from_directory_path(result.directory.path()).unwrap();
+    let uri_redaction = || {
+        let uri = uri.clone();
+        insta::dynamic_redaction(move |content, _path| {
+            content.as_str().unwrap().replace(uri.as_str(), "[tmp]/")
+        })
+    };
+
+    assert_json_snapshot!(tree, {
+        ".uri" => uri_redaction(),
+        ".children[].uri" => uri_redaction(),
+        ".children[].children[].uri" => uri_redaction()
+    });
+
+    Ok(())
+}
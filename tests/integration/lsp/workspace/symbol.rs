@@ -1,8 +1,12 @@
+use std::{thread, time::Duration};
+
 use anyhow::Result;
 use insta::{assert_json_snapshot, internals::Redaction};
 use lsp_types::{
-    request::WorkspaceSymbol, ClientCapabilities, SymbolInformation, Url, WorkspaceSymbolParams,
+    notification::DidChangeConfiguration, request::WorkspaceSymbol, ClientCapabilities,
+    DidChangeConfigurationParams, SymbolInformation, Url, WorkspaceSymbolParams,
 };
+use serde_json::json;
 
 use crate::lsp::{client::Client, fixture};
 
@@ -145,3 +149,70 @@ fn filter_bibtex() -> Result<()> {
     assert_symbols!(find_symbols(FIXTURE, "bibtex")?);
     Ok(())
 }
+
+#[test]
+fn filter_label() -> Result<()> {
+    assert_symbols!(find_symbols(FIXTURE, "label qux")?);
+    Ok(())
+}
+
+const DEFINITION_FIXTURE: &str = r#"
+%TEX main.tex
+%SRC \documentclass{article}
+%SRC \usepackage{glossaries}
+%SRC \newcommand{\R}{\mathbb{R}}
+%SRC \newenvironment{proofsketch}{}{}
+%SRC \newglossaryentry{set}{name=set, description={A collection}}
+%SRC \newacronym{lvm}{LVM}{Logical Volume Manager}
+%SRC \begin{document}
+%SRC \end{document}"#;
+
+#[test]
+fn filter_command() -> Result<()> {
+    assert_symbols!(find_symbols(DEFINITION_FIXTURE, "command")?);
+    Ok(())
+}
+
+#[test]
+fn filter_environment() -> Result<()> {
+    assert_symbols!(find_symbols(DEFINITION_FIXTURE, "environment")?);
+    Ok(())
+}
+
+#[test]
+fn filter_glossary() -> Result<()> {
+    assert_symbols!(find_symbols(DEFINITION_FIXTURE, "glossary")?);
+    Ok(())
+}
+
+#[test]
+fn settings_changed() -> Result<()> {
+    let mut client = Client::spawn()?;
+    client.initialize(ClientCapabilities::default(), None)?;
+    client.open("main.tex", "latex", r#"\mylabel{foo}"#.to_string())?;
+
+    let params = WorkspaceSymbolParams {
+        query: "foo".to_string(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+
+    let old_symbols = client
+        .request::<WorkspaceSymbol>(params.clone())?
+        .unwrap_or_default();
+
+    client.notify::<DidChangeConfiguration>(DidChangeConfigurationParams {
+        settings: json!({ "experimental": { "labelDefinitionCommands": ["mylabel"] } }),
+    })?;
+
+    thread::sleep(Duration::from_millis(500));
+    let new_symbols = client
+        .request::<WorkspaceSymbol>(params)?
+        .unwrap_or_default();
+
+    client.shutdown()?;
+
+    assert!(old_symbols.is_empty());
+    assert_eq!(new_symbols.len(), 1);
+    Ok(())
+}